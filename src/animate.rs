@@ -0,0 +1,193 @@
+//! `aoc run --animate`: step-by-step playback of the days that simulate something, drawn in the
+//! terminal with ANSI colours.
+//!
+//! Days call [`frame`] from their main loops with a closure that draws the current state. The
+//! closure only runs while an animation is playing, so with animation off each frame costs one
+//! atomic load. Frames go to stderr, leaving stdout to the answers.
+//!
+//! While playing, typing `p` and Enter pauses, Enter alone steps one frame at a time while
+//! paused, and `q` and Enter stops animating and lets the days run on at full speed.
+
+use std::fmt::Write as _;
+use std::io::{self, BufRead, Write as _};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use glam::IVec2;
+
+use crate::image::Rgb;
+use crate::utils::Grid;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PLAYER: Mutex<Option<Player>> = Mutex::new(None);
+
+const RESET: &str = "\x1b[0m";
+const DIM: &str = "\x1b[2m";
+/// Moves the cursor home, so each frame overwrites the last without the flicker of clearing.
+const HOME: &str = "\x1b[H";
+const CLEAR_LINE: &str = "\x1b[K";
+const CLEAR_BELOW: &str = "\x1b[J";
+
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    pub fps: f64,
+    /// Start paused, waiting for Enter before each frame.
+    pub paused: bool,
+}
+
+enum Command {
+    Step,
+    Toggle,
+    Quit,
+}
+
+struct Player {
+    delay: Duration,
+    paused: bool,
+    frames: u64,
+    next: Instant,
+    commands: Receiver<Command>,
+}
+
+impl Player {
+    /// Waits for the time to show the next frame, or for a step while paused. False once
+    /// animating should stop.
+    fn wait(&mut self) -> bool {
+        loop {
+            let command = if self.paused {
+                match self.commands.recv() {
+                    Ok(command) => command,
+                    // Nothing can step a paused animation without input, so play it instead.
+                    Err(_) => {
+                        self.paused = false;
+                        continue;
+                    }
+                }
+            } else {
+                match self.commands.recv_timeout(self.next.saturating_duration_since(Instant::now())) {
+                    Ok(command) => command,
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        thread::sleep(self.next.saturating_duration_since(Instant::now()));
+                        break;
+                    }
+                }
+            };
+            match command {
+                Command::Step if self.paused => break,
+                Command::Step => {}
+                Command::Toggle => self.paused = !self.paused,
+                Command::Quit => return false,
+            }
+        }
+        self.next = Instant::now() + self.delay;
+        true
+    }
+}
+
+/// Starts playing frames as the days reach them.
+pub fn start(settings: Settings) {
+    let (send, commands) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let command = match line.as_deref().map(str::trim) {
+                Ok("") => Command::Step,
+                Ok("p") => Command::Toggle,
+                Ok("q") => Command::Quit,
+                Ok(_) => continue,
+                Err(_) => return,
+            };
+            if send.send(command).is_err() {
+                return;
+            }
+        }
+    });
+    *PLAYER.lock().unwrap_or_else(|err| err.into_inner()) = Some(Player {
+        delay: Duration::from_secs_f64(1.0 / settings.fps.max(0.01)),
+        paused: settings.paused,
+        frames: 0,
+        next: Instant::now(),
+        commands,
+    });
+    ENABLED.store(true, Ordering::Relaxed);
+    eprint!("\x1b[2J");
+}
+
+#[inline]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows the state drawn by `draw` under a status line naming it `title`, then waits until the
+/// next frame is due. Does nothing, not even drawing, unless an animation is playing.
+pub fn frame(title: &str, draw: impl FnOnce() -> String) {
+    if !enabled() {
+        return;
+    }
+    let mut player = PLAYER.lock().unwrap_or_else(|err| err.into_inner());
+    let Some(player) = player.as_mut() else {
+        return;
+    };
+    player.frames += 1;
+    let help = if player.paused { "Enter steps, p plays, q stops animating" } else { "p pauses, q stops animating" };
+    let mut out = String::from(HOME);
+    for line in draw().lines() {
+        writeln!(out, "{line}{RESET}{CLEAR_LINE}").unwrap();
+    }
+    writeln!(out, "{DIM}{title}, frame {}. {help}{RESET}{CLEAR_LINE}{CLEAR_BELOW}", player.frames).unwrap();
+    let _ = io::stderr().lock().write_all(out.as_bytes());
+
+    if !player.wait() {
+        ENABLED.store(false, Ordering::Relaxed);
+    }
+}
+
+/// `text` in the 24-bit foreground `colour`.
+pub fn paint(colour: Rgb, text: impl std::fmt::Display) -> String {
+    let [r, g, b] = colour;
+    format!("\x1b[38;2;{r};{g};{b}m{text}{RESET}")
+}
+
+/// A grid drawn a character per cell, each in the colour `cell` gives it. Colours are only
+/// switched where they change, which keeps frames of big grids small, and at the start of each
+/// line since [`frame`] resets them at the end of one.
+pub fn paint_grid<T>(grid: &Grid<T>, mut cell: impl FnMut(IVec2, &T) -> (char, Rgb)) -> String {
+    let mut out = String::new();
+    let mut current = None;
+    for (point, value) in grid.iter() {
+        if point.x == 0 && point.y > 0 {
+            out.push('\n');
+            current = None;
+        }
+        let (c, colour) = cell(point, value);
+        if current != Some(colour) {
+            let [r, g, b] = colour;
+            write!(out, "\x1b[38;2;{r};{g};{b}m").unwrap();
+            current = Some(colour);
+        }
+        out.push(c);
+    }
+    out.push_str(RESET);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn painting() {
+        assert_eq!(paint([1, 2, 3], "x"), "\x1b[38;2;1;2;3mx\x1b[0m");
+        let grid = Grid::from_cells(2, vec![true, false, false, true]).unwrap();
+        let painted = paint_grid(&grid, |_, on| if *on { ('#', [9, 9, 9]) } else { ('.', [0, 0, 0]) });
+        assert_eq!(
+            painted,
+            "\x1b[38;2;9;9;9m#\x1b[38;2;0;0;0m.\n\x1b[38;2;0;0;0m.\x1b[38;2;9;9;9m#\x1b[0m"
+        );
+        // Not playing, so nothing is drawn.
+        frame("test", || unreachable!());
+    }
+}
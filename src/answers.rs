@@ -0,0 +1,53 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::input;
+
+/// A known answer: running `part` of `day` on `input` (a file in the inputs directory)
+/// should give `expected`. Stored one per line, tab separated, `#` starting a comment.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub expected: String,
+}
+
+impl Expected {
+    pub fn input_path(&self) -> PathBuf {
+        input::inputs_dir().join(&self.input)
+    }
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}\t{}\t{}", self.day, self.part, self.input, self.expected)
+    }
+}
+
+pub fn parse(text: &str) -> Result<Vec<Expected>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let [day, part, input, expected] = fields[..] else {
+                bail!("line {}: expected 4 tab separated fields, found {}", i + 1, fields.len());
+            };
+            Ok(Expected {
+                day: day.parse().with_context(|| format!("line {}: bad day", i + 1))?,
+                part: part.parse().with_context(|| format!("line {}: bad part", i + 1))?,
+                input: input.to_string(),
+                expected: expected.to_string(),
+            })
+        })
+        .collect()
+}
+
+pub fn read(path: &Path) -> Result<Vec<Expected>> {
+    let text = fs::read_to_string(path).with_context(|| format!("Reading {}", path.display()))?;
+    parse(&text).with_context(|| format!("Parsing {}", path.display()))
+}
@@ -0,0 +1,124 @@
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+
+/// Summary of repeated timings of one step, in nanoseconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub median: u64,
+    pub min: u64,
+    pub max: u64,
+    /// Interquartile range, the spread of the middle half of the samples.
+    pub iqr: u64,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<u64>) -> Stats {
+        samples.sort_unstable();
+        let at = |q: f64| samples[((samples.len() - 1) as f64 * q).round() as usize];
+        Stats {
+            median: at(0.5),
+            min: samples[0],
+            max: samples[samples.len() - 1],
+            iqr: at(0.75) - at(0.25),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayTimings>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Report> {
+        let text = fs::read_to_string(path).with_context(|| format!("Reading {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("Parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Writing {}", path.display()))
+    }
+}
+
+/// Runs `step` once to warm up, then `iterations` more times, timing each run.
+fn measure<T>(iterations: usize, mut step: impl FnMut() -> Result<T>) -> Result<Stats> {
+    step()?;
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let res = step()?;
+        samples.push(start.elapsed().as_nanos() as u64);
+        drop(res);
+    }
+    Ok(Stats::from_samples(samples))
+}
+
+pub fn bench_day(day: &Day, input: &'static str, iterations: usize) -> Result<DayTimings> {
+    let iterations = iterations.max(1);
+    let parse = measure(iterations, || (day.parse)(input))?;
+    let parsed = (day.parse)(input)?;
+    let part_one = measure(iterations, || (day.part_one)(parsed.as_ref()))?;
+    let part_two = measure(iterations, || (day.part_two)(parsed.as_ref()))?;
+    Ok(DayTimings {
+        day: day.number,
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+/// A step whose median got slower than the baseline's by more than the allowed fraction.
+#[derive(Debug, Clone)]
+pub struct Regression {
+    pub day: u8,
+    pub step: &'static str,
+    pub baseline: u64,
+    pub current: u64,
+}
+
+pub fn compare(baseline: &Report, current: &Report, threshold: f64) -> Vec<Regression> {
+    let mut res = Vec::new();
+    for now in &current.days {
+        let Some(before) = baseline.days.iter().find(|d| d.day == now.day) else {
+            continue;
+        };
+        for (step, b, c) in [
+            ("parse", before.parse, now.parse),
+            ("part one", before.part_one, now.part_one),
+            ("part two", before.part_two, now.part_two),
+        ] {
+            if c.median as f64 > b.median as f64 * (1.0 + threshold) {
+                res.push(Regression {
+                    day: now.day,
+                    step,
+                    baseline: b.median,
+                    current: c.median,
+                });
+            }
+        }
+    }
+    res
+}
+
+pub fn format_duration(nanos: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos))
+}
@@ -0,0 +1,437 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use advent_2023::animate::{self, Settings};
+use advent_2023::bench::{self, Report};
+use advent_2023::client::{self, Client};
+use advent_2023::days::{self, Day};
+use advent_2023::examples;
+use advent_2023::generate;
+use advent_2023::input::{self, Source};
+use advent_2023::ledger::{Attempt, Ledger, Verdict};
+use advent_2023::parallel;
+use advent_2023::scaffold;
+use advent_2023::trace;
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Parser, Subcommand};
+
+#[derive(Debug, Clone, Copy)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl Selection {
+    fn days(&self) -> Result<Vec<&'static Day>> {
+        match self {
+            Selection::All => Ok(days::ALL.iter().collect()),
+            Selection::Day(number) => match days::get(*number) {
+                Some(day) => Ok(vec![day]),
+                None => bail!("Day {number} is not registered"),
+            },
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "all" => Ok(Selection::All),
+            v => Ok(Selection::Day(
+                v.parse().map_err(|_| anyhow!("Bad day {v}, expected a number or \"all\""))?,
+            )),
+        }
+    }
+}
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    /// Show the days' diagnostic output, e.g. "day05=debug" or "debug,day20=off"
+    #[arg(long, global = true, env = "AOC_TRACE")]
+    trace: Option<String>,
+    /// How many threads to use for running days and the work inside them; 0 for one per core
+    #[arg(short, long, global = true, env = "AOC_JOBS", default_value_t = 1)]
+    jobs: usize,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every registered day with "all"
+    Run {
+        day: Selection,
+        /// Only run this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        animate: AnimateArgs,
+    },
+    /// Time parsing and both parts of one day, or of every day with "all"
+    Bench {
+        day: Selection,
+        #[arg(long, default_value_t = 20)]
+        iterations: usize,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Where to write the results as JSON
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/bench/latest.json"))]
+        output: PathBuf,
+        /// Results to compare against; missing baselines are ignored
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/bench/baseline.json"))]
+        baseline: PathBuf,
+        /// Overwrite the baseline with these results
+        #[arg(long)]
+        save_baseline: bool,
+        /// How much slower than the baseline a median may get before it counts as a regression
+        #[arg(long, default_value_t = 0.1)]
+        threshold: f64,
+    },
+    /// Start a new day from the template and register it with the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = input::YEAR)]
+        year: u16,
+    },
+    /// Download puzzle inputs into the inputs directory, skipping any already cached
+    Fetch {
+        #[arg(required = true)]
+        days: Vec<u8>,
+        #[command(flatten)]
+        server: Server,
+    },
+    /// Save the examples and expected answers from a saved puzzle page
+    Examples {
+        day: u8,
+        /// The puzzle page, saved as HTML
+        page: PathBuf,
+        #[arg(long, default_value_t = input::YEAR)]
+        year: u16,
+        /// Replace example files that already have content
+        #[arg(long)]
+        force: bool,
+    },
+    /// Draw a day's puzzle to a .png or .ppm file
+    Render {
+        day: u8,
+        output: PathBuf,
+        /// How many pixels wide to draw each pixel of the day's picture
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..))]
+        scale: u16,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Write a random input for stress testing; the same size and seed give the same input
+    Generate {
+        day: u8,
+        /// How big to make it; what this counts depends on the day, and defaults to about the
+        /// size of the real input
+        #[arg(long)]
+        size: Option<usize>,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Write the input here instead of to stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Submit an answer and record the verdict in the ledger
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// The answer to send; computed from the real input when left out
+        answer: Option<String>,
+        #[command(flatten)]
+        server: Server,
+    },
+}
+
+#[derive(clap::Args)]
+struct InputArgs {
+    /// Use the example input from the puzzle text instead of the real input
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+    /// Read the input from this file instead of the inputs directory
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+impl InputArgs {
+    fn source(self) -> Source {
+        match (self.example, self.input) {
+            (_, Some(path)) => Source::Path(path),
+            (true, None) => Source::Example,
+            (false, None) => Source::Real,
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct AnimateArgs {
+    /// Play the simulating days (14, 16 and 20) step by step in the terminal. Type p and Enter
+    /// to pause or play, Enter alone to step while paused, and q and Enter to stop animating
+    #[arg(long)]
+    animate: bool,
+    /// Frames per second while playing
+    #[arg(long, default_value_t = 10.0, requires = "animate")]
+    fps: f64,
+    /// Start paused, stepping a frame at a time
+    #[arg(long, requires = "animate")]
+    paused: bool,
+}
+
+#[derive(clap::Args)]
+struct Server {
+    #[arg(long, default_value_t = input::YEAR)]
+    year: u16,
+    /// Where to reach the puzzle server, e.g. a local stub for testing
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl Server {
+    fn client(&self) -> Result<Client> {
+        Ok(Client::new(&self.base_url, self.year, client::session()?))
+    }
+}
+
+/// Runs a day, writing its report to `out` rather than stdout so days run side by side can
+/// still be printed in order.
+fn run_day(day: &Day, part: Option<u8>, source: &Source, out: &mut String) -> Result<()> {
+    writeln!(out, "Day {:02}", day.number)?;
+    let input = input::load(day.number, source)?;
+    let parsed = (day.parse)(input)?;
+    if part != Some(2) {
+        let part_1_ans = (day.part_one)(parsed.as_ref())?;
+        writeln!(out, "Part One answer: {part_1_ans}")?;
+    }
+    if part != Some(1) {
+        let part_2_ans = (day.part_two)(parsed.as_ref())?;
+        writeln!(out, "Part Two answer: {part_2_ans}")?;
+    }
+    Ok(())
+}
+
+fn solve(number: u8, part: u8) -> Result<String> {
+    let day = Selection::Day(number).days()?[0];
+    let parsed = (day.parse)(input::load(number, &Source::Real)?)?;
+    match part {
+        1 => (day.part_one)(parsed.as_ref()),
+        _ => (day.part_two)(parsed.as_ref()),
+    }
+}
+
+fn submit(day: u8, part: u8, answer: Option<String>, server: &Server) -> Result<()> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => solve(day, part)?,
+    };
+    let mut ledger = Ledger::open(Ledger::default_path())?;
+    ledger.check(server.year, day, part, &answer)?;
+
+    let verdict = server.client()?.submit(day, part, &answer)?;
+    ledger.record(Attempt {
+        year: server.year,
+        day,
+        part,
+        value: answer.clone(),
+        verdict,
+    })?;
+    match verdict {
+        Verdict::Correct => println!("{answer} is correct"),
+        Verdict::Wait => println!("Submitted too recently, {answer} was not checked"),
+        wrong => println!("{answer} is wrong: {wrong}"),
+    }
+    Ok(())
+}
+
+fn run_bench(
+    selection: Selection,
+    iterations: usize,
+    source: &Source,
+    output: &Path,
+    baseline: &Path,
+    save_baseline: bool,
+    threshold: f64,
+) -> Result<()> {
+    let mut report = Report {
+        iterations,
+        days: Vec::new(),
+    };
+    println!("{:<5} {:<9} {:>12} {:>12} {:>12} {:>12}", "day", "step", "median", "iqr", "min", "max");
+    for day in selection.days()? {
+        let input = input::load(day.number, source)?;
+        let timings = bench::bench_day(day, input, iterations)
+            .with_context(|| format!("Day {:02}", day.number))?;
+        for (step, stats) in [
+            ("parse", timings.parse),
+            ("part one", timings.part_one),
+            ("part two", timings.part_two),
+        ] {
+            println!(
+                "{:<5} {step:<9} {:>12} {:>12} {:>12} {:>12}",
+                format!("{:02}", day.number),
+                bench::format_duration(stats.median),
+                bench::format_duration(stats.iqr),
+                bench::format_duration(stats.min),
+                bench::format_duration(stats.max),
+            );
+        }
+        report.days.push(timings);
+    }
+    report.save(output)?;
+    println!("Wrote {}", output.display());
+
+    if save_baseline {
+        report.save(baseline)?;
+        println!("Saved baseline {}", baseline.display());
+        return Ok(());
+    }
+    if !baseline.exists() {
+        return Ok(());
+    }
+    let regressions = bench::compare(&Report::load(baseline)?, &report, threshold);
+    for r in &regressions {
+        println!(
+            "REGRESSION day {:02} {}: {} -> {}",
+            r.day,
+            r.step,
+            bench::format_duration(r.baseline),
+            bench::format_duration(r.current),
+        );
+    }
+    if !regressions.is_empty() {
+        bail!("{} step(s) slower than {}", regressions.len(), baseline.display());
+    }
+    println!("No regressions against {}", baseline.display());
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if let Some(spec) = &cli.trace {
+        trace::init(spec)?;
+    }
+    parallel::set_threads(cli.jobs);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            animate,
+        } => {
+            if animate.animate {
+                // Frames from days running side by side would draw over each other.
+                parallel::set_threads(1);
+                animate::start(Settings {
+                    fps: animate.fps,
+                    paused: animate.paused,
+                });
+            }
+            let source = input.source();
+            let reports = parallel::map(&day.days()?, |day| {
+                let mut out = String::new();
+                let result = run_day(day, part, &source, &mut out);
+                (day.number, out, result)
+            });
+            for (number, out, result) in reports {
+                print!("{out}");
+                result.with_context(|| format!("Day {number:02}"))?;
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            input,
+            output,
+            baseline,
+            save_baseline,
+            threshold,
+        } => run_bench(
+            day,
+            iterations,
+            &input.source(),
+            &output,
+            &baseline,
+            save_baseline,
+            threshold,
+        )?,
+        Command::New { day, year } => {
+            for path in scaffold::create(day, year)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Command::Fetch { days, server } => {
+            let client = server.client()?;
+            for day in days {
+                let (path, downloaded) = client.fetch_input(day)?;
+                if downloaded {
+                    println!("Day {day:02}: saved {}", path.display());
+                } else {
+                    println!("Day {day:02}: already cached at {}", path.display());
+                }
+            }
+        }
+        Command::Examples {
+            day,
+            page,
+            year,
+            force,
+        } => {
+            let html = std::fs::read_to_string(&page)
+                .with_context(|| format!("Reading {}", page.display()))?;
+            let found = examples::extract(&html)?;
+            for example in &found {
+                println!("Part {}: expecting {}", example.part, example.expected);
+            }
+            for path in examples::save(day, year, &found, force)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Command::Render {
+            day,
+            output,
+            scale,
+            input,
+        } => {
+            let day = Selection::Day(day).days()?[0];
+            let parsed = (day.parse)(input::load(day.number, &input.source())?)?;
+            (day.render)(parsed.as_ref())?.scaled(scale as usize).save(&output)?;
+            println!("Wrote {}", output.display());
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let Some((_, default_size)) = generate::describe(day) else {
+                bail!("There is no generator for day {day}, only for days {:?}", generate::DAYS);
+            };
+            let text = generate::generate(day, size.unwrap_or(default_size), seed)?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, text).with_context(|| format!("Writing {}", path.display()))?;
+                    println!("Wrote {}", path.display());
+                }
+                None => print!("{text}"),
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            server,
+        } => submit(day, part, answer, &server)?,
+    }
+
+    Ok(())
+}
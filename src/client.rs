@@ -0,0 +1,277 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::input;
+use crate::ledger::Verdict;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/pjhaugh/advent-2023 by pjhaugh";
+
+/// Minimum gap between two requests to the puzzle server, shared by every `aoc` process
+/// through a timestamp file next to the inputs.
+const THROTTLE: Duration = Duration::from_secs(5);
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to `~/.aocsession`.
+pub fn session() -> Result<String> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+    let home = std::env::var("HOME").context("HOME is not set")?;
+    let path = Path::new(&home).join(".aocsession");
+    let session = fs::read_to_string(&path).with_context(|| {
+        format!("No AOC_SESSION set and could not read {}", path.display())
+    })?;
+    Ok(session.trim().to_string())
+}
+
+pub struct Client {
+    base_url: String,
+    year: u16,
+    session: String,
+    agent: ureq::Agent,
+    /// Where inputs are cached and the throttle's timestamp is kept.
+    dir: PathBuf,
+    throttle: Duration,
+}
+
+impl Client {
+    pub fn new(base_url: &str, year: u16, session: String) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            year,
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            dir: input::inputs_dir().to_path_buf(),
+            throttle: THROTTLE,
+        }
+    }
+
+    /// Caches inputs in `dir` instead of the inputs directory, and throttles only against other
+    /// clients using the same one.
+    pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Client {
+        self.dir = dir.into();
+        self
+    }
+
+    pub fn with_throttle(mut self, throttle: Duration) -> Client {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{day}", self.base_url, self.year)
+    }
+
+    /// Sleeps until at least `throttle` has passed since the last request, then records now.
+    fn wait_turn(&self) -> Result<()> {
+        let stamp = self.dir.join(".last-request");
+        let last = fs::metadata(&stamp).and_then(|m| m.modified()).ok();
+        if let Some(elapsed) = last.and_then(|l| SystemTime::now().duration_since(l).ok()) {
+            if elapsed < self.throttle {
+                thread::sleep(self.throttle - elapsed);
+            }
+        }
+        fs::create_dir_all(&self.dir)?;
+        fs::write(&stamp, b"").with_context(|| format!("Updating {}", stamp.display()))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn get(&self, url: &str) -> Result<String> {
+        self.wait_turn()?;
+        let response = self.agent.get(url).set("Cookie", &self.cookie()).call();
+        read_response(url, response)
+    }
+
+    pub fn post_form(&self, url: &str, form: &[(&str, &str)]) -> Result<String> {
+        self.wait_turn()?;
+        let response = self
+            .agent
+            .post(url)
+            .set("Cookie", &self.cookie())
+            .send_form(form);
+        read_response(url, response)
+    }
+
+    /// Downloads a day's input into the inputs directory, unless a copy is already cached
+    /// there. An empty file, like the placeholder `aoc new` leaves, doesn't count as a copy.
+    /// Returns the cached path and whether anything was downloaded.
+    pub fn fetch_input(&self, day: u8) -> Result<(PathBuf, bool)> {
+        let path = self.dir.join(input::real_name(day, self.year));
+        if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+            return Ok((path, false));
+        }
+        let text = self.get(&format!("{}/input", self.day_url(day)))?;
+        fs::create_dir_all(&self.dir)?;
+        fs::write(&path, text).with_context(|| format!("Writing {}", path.display()))?;
+
+        let example = self.dir.join(input::example_name(day, self.year));
+        if !example.exists() {
+            fs::write(&example, "").with_context(|| format!("Writing {}", example.display()))?;
+        }
+        Ok((path, true))
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        let level = part.to_string();
+        let body = self.post_form(
+            &format!("{}/answer", self.day_url(day)),
+            &[("level", &level), ("answer", answer)],
+        )?;
+        parse_verdict(&body)
+    }
+}
+
+fn parse_verdict(body: &str) -> Result<Verdict> {
+    if body.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if body.contains("too high") {
+        Ok(Verdict::TooHigh)
+    } else if body.contains("too low") {
+        Ok(Verdict::TooLow)
+    } else if body.contains("That's not the right answer") {
+        Ok(Verdict::Wrong)
+    } else if body.contains("You gave an answer too recently") {
+        Ok(Verdict::Wait)
+    } else if body.contains("You don't seem to be solving the right level") {
+        bail!("The server won't take an answer for this part: it is either solved or still locked")
+    } else {
+        bail!("Could not understand the server's response:\n{}", body.trim())
+    }
+}
+
+fn read_response(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            bail!("{url} returned {code}: {}", body.trim())
+        }
+        Err(err) => Err(anyhow!(err).context(format!("Requesting {url}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
+
+    use super::*;
+
+    /// A puzzle server on a local port that answers every request with `respond(request line,
+    /// body)`. Returns its base URL and the requests it has seen so far.
+    fn stub(respond: impl Fn(&str, &str) -> String + Send + 'static) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = seen.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let mut length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let body = String::from_utf8(body).unwrap();
+                let request = request.trim().to_string();
+                let reply = respond(&request, &body);
+                log.lock().unwrap().push(format!("{request} {body}").trim().to_string());
+                write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{reply}", reply.len()).unwrap();
+            }
+        });
+        (base_url, seen)
+    }
+
+    /// A fresh directory for one test's cached inputs.
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn fetch_caches_and_throttles() {
+        let (base_url, seen) = stub(|request, _| format!("input for {request}\n"));
+        let dir = scratch("fetch");
+        let throttle = Duration::from_millis(300);
+        let client = Client::new(&base_url, 2023, "secret".into()).with_dir(&dir).with_throttle(throttle);
+
+        // An empty placeholder is not a cached copy.
+        fs::write(dir.join("input-01-2023.txt"), "").unwrap();
+        let (path, downloaded) = client.fetch_input(1).unwrap();
+        assert!(downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input for GET /2023/day/1/input HTTP/1.1\n");
+        assert!(dir.join("test-01-2023.txt").is_file());
+
+        assert_eq!(client.fetch_input(1).unwrap(), (path, false));
+        assert_eq!(seen.lock().unwrap().len(), 1);
+
+        // Back to back with the first download, so this one waits its turn. Allow some slack for
+        // the stamp's modification time being coarser than the clock.
+        let started = Instant::now();
+        client.fetch_input(2).unwrap();
+        assert!(started.elapsed() >= throttle / 2, "only waited {:?}", started.elapsed());
+        assert_eq!(*seen.lock().unwrap(), ["GET /2023/day/1/input HTTP/1.1", "GET /2023/day/2/input HTTP/1.1"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn submit_reads_verdicts() {
+        let (base_url, seen) = stub(|_, body| {
+            let page = match body.rsplit_once("answer=").map(|(_, answer)| answer) {
+                Some("42") => "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+                Some("99") => "<article><p>That's not the right answer; your answer is too high.</p></article>",
+                Some("7") => "<article><p>That's not the right answer; your answer is too low.</p></article>",
+                Some("50") => "<article><p>That's not the right answer. If you're stuck, ...</p></article>",
+                Some("1") => "<article><p>You gave an answer too recently; you have 30s left to wait.</p></article>",
+                Some("2") => "<article><p>You don't seem to be solving the right level.</p></article>",
+                _ => "<html>Something else entirely</html>",
+            };
+            page.to_string()
+        });
+        let dir = scratch("submit");
+        let client = Client::new(&base_url, 2023, "secret".into()).with_dir(&dir).with_throttle(Duration::ZERO);
+
+        for (answer, verdict) in [
+            ("42", Verdict::Correct),
+            ("99", Verdict::TooHigh),
+            ("7", Verdict::TooLow),
+            ("50", Verdict::Wrong),
+            ("1", Verdict::Wait),
+        ] {
+            assert_eq!(client.submit(5, 2, answer).unwrap(), verdict, "{answer}");
+        }
+        assert!(client.submit(5, 2, "2").unwrap_err().to_string().contains("either solved or still locked"));
+        assert!(client.submit(5, 2, "3").unwrap_err().to_string().contains("Something else entirely"));
+        assert_eq!(seen.lock().unwrap()[0], "POST /2023/day/5/answer HTTP/1.1 level=2&answer=42");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
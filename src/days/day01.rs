@@ -1,14 +1,14 @@
-use std::fmt::Write;
-use std::str::FromStr;
-
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::anychar;
 use nom::combinator::map_res;
-use nom::multi::{many0, many_till};
+use nom::multi::many_till;
 use nom::IResult;
 
+use crate::parse::{self, error_at};
+use crate::solution::Solution;
+
 fn parse_digit(input: &str) -> Result<u32> {
     match input {
         "0" => Ok(0),
@@ -126,40 +126,56 @@ fn parse_num_backwards(input: &str) -> IResult<&str, u32> {
     Ok((input, res))
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("../../inputs/input-01-2023.txt");
-    let part_one_ans = part_one(input)?;
-    println!("Part one: {part_one_ans}");
+/// The first and last digit on `line`, counting spelled-out ones, as a two-digit number.
+fn calibration(line: &'static str) -> Result<u32> {
+    let (_, first) = parse_num(line).map_err(|_| error_at(line, "Expected a digit or a spelled-out digit"))?;
+    let reversed = line.chars().rev().collect::<String>();
+    let (_, last) = parse_num_backwards(&reversed).map_err(|_| anyhow!("No digit in {line:?} backwards"))?;
+    Ok(first * 10 + last)
+}
+
+pub struct Day01;
 
-    let part_two_ans = part_two(input)?;
-    println!("Part two: {part_two_ans}");
+impl Solution for Day01 {
+    const DAY: u8 = 1;
 
-    Ok(())
-}
+    type Parsed = Vec<&'static str>;
+    type PartOne = u32;
+    type PartTwo = u32;
 
-fn part_one(input: &str) -> Result<u32> {
-    Ok(input
-        .lines()
-        .map(|s| {
-            let mut o: String = Default::default();
-            o.write_char(s.chars().filter(char::is_ascii_digit).next().unwrap())
-                .unwrap();
-            o.write_char(s.chars().rev().filter(char::is_ascii_digit).next().unwrap())
-                .unwrap();
-            u32::from_str(o.as_str()).unwrap()
-        })
-        .sum::<u32>())
+    /// Every line needs at least a spelled-out digit for part two. Part one's plain digits are
+    /// checked there, since part two's lines don't need any.
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        parse::lines(input, |line| calibration(line).map(|_| line))
+    }
+
+    fn part_one(lines: &Self::Parsed) -> Result<u32> {
+        lines
+            .iter()
+            .map(|line| {
+                let digits = line.chars().filter_map(|c| c.to_digit(10)).collect::<Vec<_>>();
+                match (digits.first(), digits.last()) {
+                    (Some(first), Some(last)) => Ok(first * 10 + last),
+                    _ => bail!("No digit in {line:?}"),
+                }
+            })
+            .sum()
+    }
+
+    fn part_two(lines: &Self::Parsed) -> Result<u32> {
+        lines.iter().map(|line| calibration(line)).sum()
+    }
 }
 
-fn part_two(input: &str) -> Result<u32> {
-    let mut x = 0;
-    for line in input.lines() {
-        let (_, tens) = many0(parse_num)(line).unwrap();
-        let rev = line.to_string().chars().rev().collect::<String>();
-        let y = rev.as_str();
-        let (_, ones) = many0(parse_num_backwards)(y).unwrap();
-        let v = tens.first().unwrap() * 10 + ones.first().unwrap();
-        x += v;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_without_digits() {
+        assert!(Day01::parse("two1nine\nnodigits").is_err());
+        let parsed = Day01::parse("eightwothree\n7pqrstsixteen").unwrap();
+        assert_eq!(Day01::part_two(&parsed).unwrap(), 83 + 76);
+        assert!(Day01::part_one(&parsed).is_err());
     }
-    Ok(x)
 }
@@ -8,9 +8,13 @@ use nom::multi::separated_list1;
 use nom::sequence::{pair, preceded};
 use nom::IResult;
 
-use crate::Color::{Blue, Green, Red};
+use crate::parse;
+use crate::solution::Solution;
+use crate::utils::parsers::full;
 
-#[derive(Debug, Clone, Copy)]
+use self::Color::{Blue, Green, Red};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Color {
     Red,
     Blue,
@@ -32,14 +36,14 @@ impl FromStr for Color {
 
 type Group = Vec<(u64, Color)>;
 
-#[derive(Debug)]
-struct Game {
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
     id: u64,
     phases: Vec<Group>,
 }
 
 fn parse_num(input: &str) -> Result<u64> {
-    Ok(u64::from_str_radix(input, 10)?)
+    Ok(input.parse::<u64>()?)
 }
 
 fn parse_game(input: &str) -> IResult<&str, Game> {
@@ -58,18 +62,6 @@ fn parse_game(input: &str) -> IResult<&str, Game> {
     Ok((input, Game { id, phases }))
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("../../inputs/input-02-2023.txt");
-
-    let part_1_ans = part_one(input)?;
-    println!("Part One answer: {part_1_ans}");
-
-    let part_2_ans = part_two(input)?;
-    println!("Part Two answer: {part_2_ans}");
-
-    Ok(())
-}
-
 fn game_possible(game: &Game, max_red: u64, max_blue: u64, max_green: u64) -> bool {
     for phase in &game.phases {
         let (mut red, mut blue, mut green) = (0, 0, 0);
@@ -93,22 +85,6 @@ fn game_possible(game: &Game, max_red: u64, max_blue: u64, max_green: u64) -> bo
     true
 }
 
-fn part_one(input: &str) -> Result<u64> {
-    let (red, blue, green) = (12, 14, 13);
-
-    Ok(input
-        .lines()
-        .filter_map(|line| {
-            let (_, game) = parse_game(line).expect("Bad input");
-            if game_possible(&game, red, blue, green) {
-                Some(game.id)
-            } else {
-                None
-            }
-        })
-        .sum())
-}
-
 fn calc_power(game: &Game) -> u64 {
     let (mut max_red, mut max_blue, mut max_green) = (0_u64, 0, 0);
     for phase in &game.phases {
@@ -123,12 +99,71 @@ fn calc_power(game: &Game) -> u64 {
     max_red * max_green * max_blue
 }
 
-fn part_two(input: &str) -> Result<u64> {
-    Ok(input
-        .lines()
-        .map(|line| {
-            let (_, game) = parse_game(line).expect("Bad input");
-            calc_power(&game)
-        })
-        .sum())
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Game>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        parse::lines(input, |line| Ok(full(parse_game)(line)?.1))
+    }
+
+    fn part_one(games: &Self::Parsed) -> Result<u64> {
+        let (red, blue, green) = (12, 14, 13);
+
+        Ok(games
+            .iter()
+            .filter_map(|game| {
+                if game_possible(game, red, blue, green) {
+                    Some(game.id)
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
+
+    fn part_two(games: &Self::Parsed) -> Result<u64> {
+        Ok(games.iter().map(calc_power).sum())
+    }
+}
+
+/// Games numbered from 1, each with up to four draws of at most 20 cubes of a colour.
+#[cfg(test)]
+pub(crate) mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    fn game(rng: &mut Rng, id: u64) -> Game {
+        let phases = (0..rng.range(1..=4))
+            .map(|_| {
+                (0..rng.range(1..=3))
+                    .map(|_| (rng.range(0..=20) as u64, *rng.pick(&[Red, Blue, Green])))
+                    .collect()
+            })
+            .collect();
+        Game { id, phases }
+    }
+
+    pub fn games(rng: &mut Rng) -> Vec<Game> {
+        (1..=rng.range(1..=5) as u64).map(|id| game(rng, id)).collect()
+    }
+
+    pub fn print(games: &[Game]) -> String {
+        games
+            .iter()
+            .map(|game| {
+                let phases = game.phases.iter().map(|phase| {
+                    phase.iter().map(|(n, color)| format!("{n} {}", format!("{color:?}").to_lowercase())).join(", ")
+                });
+                format!("Game {}: {}\n", game.id, phases.format("; "))
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,103 @@
+use anyhow::Result;
+use glam::IVec2;
+use tracing::{debug, trace};
+
+use crate::solution::Solution;
+use crate::utils::Grid;
+
+type Point = IVec2;
+
+struct Number {
+    value: usize,
+    cells: Vec<Point>,
+}
+
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+fn parse_schematic(input: &'static str) -> Result<Schematic> {
+    let grid = Grid::parse(input, Ok)?;
+    let mut numbers: Vec<Number> = Default::default();
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let len = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
+            if len > 0 {
+                numbers.push(Number {
+                    value: row[x..x + len].iter().collect::<String>().parse()?,
+                    cells: (x..x + len).map(|x| Point::new(x as i32, y as i32)).collect(),
+                });
+            }
+            x += len.max(1);
+        }
+    }
+    for (point, c) in grid.iter() {
+        if is_symbol(*c) {
+            trace!("Found {c} at {}, {}", point.x, point.y);
+        }
+    }
+    Ok(Schematic { grid, numbers })
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+fn adjacent(grid: &Grid<char>, number: &Number, point: Point) -> bool {
+    number.cells.iter().any(|cell| grid.neighbours8(*cell).any(|p| p == point))
+}
+
+fn symbol_adjacent(grid: &Grid<char>, number: &Number) -> bool {
+    for cell in &number.cells {
+        if grid.neighbours8(*cell).any(|p| is_symbol(grid[p])) {
+            trace!("Found at {}, {}", cell.x, cell.y);
+            return true;
+        }
+    }
+    false
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Schematic;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        parse_schematic(input)
+    }
+
+    fn part_one(schematic: &Self::Parsed) -> Result<usize> {
+        let mut sum = 0;
+        for number in &schematic.numbers {
+            trace!("Checking around {}", number.value);
+            if symbol_adjacent(&schematic.grid, number) {
+                sum += number.value;
+            }
+        }
+        Ok(sum)
+    }
+
+    fn part_two(schematic: &Self::Parsed) -> Result<usize> {
+        let mut res = 0;
+        let gears = schematic.grid.iter().filter_map(|(point, c)| (*c == '*').then_some(point));
+        for gear in gears {
+            let nums = schematic
+                .numbers
+                .iter()
+                .filter(|number| adjacent(&schematic.grid, number, gear))
+                .map(|number| number.value)
+                .collect::<Vec<_>>();
+            debug!("Gear {}, {} next to {nums:?}", gear.x, gear.y);
+            if let [a, b] = nums[..] {
+                res += a * b;
+            }
+        }
+        Ok(res)
+    }
+}
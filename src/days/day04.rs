@@ -0,0 +1,104 @@
+use anyhow::Result;
+use nom::IResult;
+use nom::bytes::complete::tag;
+use nom::sequence::preceded;
+
+use crate::parse;
+use crate::solution::Solution;
+use crate::utils::parsers::{full, labelled, numbers, unsigned};
+use crate::utils::ws;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    _id: u32,
+    winning_nums: Vec<u32>,
+    my_nums: Vec<u32>,
+}
+
+impl Game {
+    fn wins(&self) -> usize {
+        self.my_nums
+            .iter()
+            .filter(|n| self.winning_nums.contains(n))
+            .count()
+    }
+}
+
+fn parse_game(input: &str) -> IResult<&str, Game> {
+    let (input, id) = labelled("Card", unsigned)(input)?;
+    let (input, winning_nums) = preceded(ws(tag(":")), numbers)(input)?;
+    let (input, my_nums) = preceded(ws(tag("|")), numbers)(input)?;
+    Ok((
+        input,
+        Game {
+            _id: id,
+            winning_nums,
+            my_nums,
+        },
+    ))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Game>;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        parse::lines(input, |line| Ok(full(parse_game)(line)?.1))
+    }
+
+    fn part_one(games: &Self::Parsed) -> Result<u32> {
+        let mut res: u32 = 0;
+        for game in games {
+            let wins = game.wins();
+            if wins > 0 {
+                res += 2_u32.pow((wins - 1) as u32);
+            }
+        }
+        Ok(res)
+    }
+
+    fn part_two(games: &Self::Parsed) -> Result<usize> {
+        let mut counts = vec![1; games.len()];
+        for (index, game) in games.iter().enumerate() {
+            let wins = game.wins();
+            let num = counts[index];
+            for count in &mut counts[index + 1..=index + wins] {
+                *count += num;
+            }
+        }
+        Ok(counts.iter().sum())
+    }
+}
+
+/// Cards numbered from 1 with up to ten numbers a side, printed in right-aligned columns as in
+/// the puzzle.
+#[cfg(test)]
+pub(crate) mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    fn numbers(rng: &mut Rng) -> Vec<u32> {
+        (0..rng.range(1..=10)).map(|_| rng.range(1..=99) as u32).collect()
+    }
+
+    pub fn games(rng: &mut Rng) -> Vec<Game> {
+        (1..=rng.range(1..=5) as u32)
+            .map(|id| Game { _id: id, winning_nums: numbers(rng), my_nums: numbers(rng) })
+            .collect()
+    }
+
+    pub fn print(games: &[Game]) -> String {
+        let numbers = |nums: &[u32]| nums.iter().map(|n| format!("{n:>2}")).join(" ");
+        games
+            .iter()
+            .map(|game| format!("Card {:>3}: {} | {}\n", game._id, numbers(&game.winning_nums), numbers(&game.my_nums)))
+            .collect()
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{bail, Context, Result};
+use nom::character::complete::{line_ending, not_line_ending, space1};
+use nom::sequence::{preceded, terminated, tuple};
+use nom::IResult;
+use tracing::{debug, trace};
+
+use crate::solution::Solution;
+use crate::utils::interval::{Interval, IntervalSet};
+use crate::utils::parsers::{blank_line, full, labelled, lines, numbers, sections, unsigned};
+
+#[derive(Debug)]
+struct Mapping {
+    dest_start: i64,
+    source_start: i64,
+    len: i64,
+}
+
+impl Mapping {
+    fn source(&self) -> Interval {
+        Interval::from_len(self.source_start, self.len)
+    }
+}
+
+struct Map {
+    mappings: Vec<Mapping>,
+    name: &'static str,
+}
+
+fn convert(map: &Map, val: &i64) -> i64 {
+    for mapping in &map.mappings {
+        if mapping.source().contains(*val) {
+            return mapping.dest_start + (val - mapping.source_start);
+        }
+    }
+    *val
+}
+
+/// Moves the parts of `values` each mapping covers, leaving the rest where they are.
+fn convert_set(map: &Map, values: &IntervalSet) -> IntervalSet {
+    debug!("Processing {values:?} using {}", map.name);
+    let mut unmapped = values.clone();
+    let mut mapped = IntervalSet::new();
+    for mapping in &map.mappings {
+        let source = IntervalSet::from(mapping.source());
+        let moved = unmapped.intersection(&source).shift(mapping.dest_start - mapping.source_start);
+        trace!("Mapped {:?} to {moved:?} based on {mapping:?}", unmapped.intersection(&source));
+        mapped = mapped.union(&moved);
+        unmapped = unmapped.difference(&source);
+    }
+    trace!("No mapping for {unmapped:?}");
+    mapped.union(&unmapped)
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<Map>,
+}
+
+fn parse_mapping(input: &str) -> IResult<&str, Mapping> {
+    let (input, (dest_start, source_start, len)) = tuple((
+        unsigned,
+        preceded(space1, unsigned),
+        preceded(space1, unsigned),
+    ))(input)?;
+    Ok((input, Mapping { dest_start, source_start, len }))
+}
+
+fn parse_map(input: &'static str) -> IResult<&'static str, Map> {
+    let (input, name) = terminated(not_line_ending, line_ending)(input)?;
+    let (input, mappings) = lines(parse_mapping)(input)?;
+    Ok((input, Map { mappings, name }))
+}
+
+fn parse_almanac(input: &'static str) -> IResult<&'static str, Almanac> {
+    let (input, seeds) = labelled("seeds:", numbers)(input)?;
+    let (input, maps) = preceded(blank_line, sections(parse_map))(input)?;
+    Ok((input, Almanac { seeds, maps }))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = Almanac;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(full(parse_almanac)(input)?.1)
+    }
+
+    fn part_one(almanac: &Self::Parsed) -> Result<i64> {
+        let mut seeds = almanac.seeds.clone();
+
+        for map in &almanac.maps {
+            for seed in seeds.iter_mut() {
+                *seed = convert(map, seed);
+            }
+        }
+
+        seeds.into_iter().min().context("No seeds")
+    }
+
+    fn part_two(almanac: &Self::Parsed) -> Result<i64> {
+        if almanac.seeds.len() % 2 != 0 {
+            bail!("Seeds don't come in start and length pairs");
+        }
+        let mut seeds = almanac
+            .seeds
+            .chunks(2)
+            .map(|pair| Interval::from_len(pair[0], pair[1]))
+            .collect::<IntervalSet>();
+
+        debug!("Seed ranges {seeds:?}");
+
+        for map in &almanac.maps {
+            seeds = convert_set(map, &seeds);
+        }
+
+        debug!("Location ranges {seeds:?}");
+
+        seeds.min().context("No seeds")
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::{bail, Result};
+use std::iter::zip;
+use nom::character::complete::line_ending;
+use nom::sequence::separated_pair;
+use tracing::debug;
+
+use crate::solution::Solution;
+use crate::utils::parsers::{full, labelled, numbers};
+
+pub struct Races {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+/// Part two reads each line as a single number with the spaces between the columns removed.
+fn concat(nums: &[u64]) -> Result<u64> {
+    Ok(nums.iter().map(u64::to_string).collect::<String>().parse()?)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = Races;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        let (_, (times, distances)) = full(separated_pair(
+            labelled("Time:", numbers),
+            line_ending,
+            labelled("Distance:", numbers),
+        ))(input)?;
+        if times.len() != distances.len() {
+            bail!("{} times but {} distances", times.len(), distances.len());
+        }
+
+        debug!("Times {times:?}");
+        debug!("Distances {distances:?}");
+
+        Ok(Races { times, distances })
+    }
+
+    fn part_one(races: &Self::Parsed) -> Result<u64> {
+        let mut res = 1;
+
+        for (time, distance) in zip(&races.times, &races.distances) {
+            res *= (0..*time).filter(|t| t * (time - t) > *distance).count() as u64;
+        }
+
+        Ok(res)
+    }
+
+    fn part_two(races: &Self::Parsed) -> Result<u64> {
+        let time = concat(&races.times)?;
+        let distance = concat(&races.distances)?;
+
+        let mut res = 0;
+        let mut winning = false;
+
+        for t in 0..time {
+            if t * (time - t) > distance {
+                res += 1;
+                winning = true;
+            } else if winning {
+                break
+            }
+        }
+
+        Ok(res)
+    }
+}
@@ -4,9 +4,12 @@ use std::iter::zip;
 
 use anyhow::Result;
 use itertools::Itertools;
-use nom::Parser;
 
-use crate::Score::{FiveOfAKind, FourOfAKind, FullHouse, High, Pair, ThreeOfAKind, TwoPair};
+use crate::parse::{self, error_at, parse_at};
+use crate::solution::Solution;
+
+use self::Score::{FiveOfAKind, FourOfAKind, FullHouse, High, Pair, ThreeOfAKind, TwoPair};
+
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
 enum Score {
@@ -19,7 +22,7 @@ enum Score {
     FiveOfAKind,
 }
 
-#[derive(Debug, Ord, Eq)]
+#[derive(Debug, Eq)]
 struct Hand {
     score: Score,
     cards: Vec<u64>,
@@ -34,22 +37,34 @@ impl PartialEq<Self> for Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
         if self.score != other.score {
-            return Some(self.score.cmp(&other.score));
+            return self.score.cmp(&other.score);
         }
         for (a, b) in zip(&self.cards, &other.cards) {
             if a < b {
-                return Some(Ordering::Less);
+                return Ordering::Less;
             }
             if a > b {
-                return Some(Ordering::Greater);
+                return Ordering::Greater;
             }
         }
-        Some(Ordering::Equal)
+        Ordering::Equal
     }
 }
 
-fn get_score(cards: &Vec<u64>) -> Score {
+#[derive(Debug)]
+pub struct Play {
+    cards: &'static str,
+    bid: u64,
+}
+
+fn get_score(cards: &[u64]) -> Score {
     let mut map = HashMap::new();
 
     *map.entry(cards[0]).or_insert(0) += 1;
@@ -74,11 +89,11 @@ fn get_score(cards: &Vec<u64>) -> Score {
     }
 }
 
-fn value_hand(cards: &str, bid: &str) -> Result<Hand> {
+fn value_hand(cards: &str, bid: u64) -> Result<Hand> {
     let cards = cards
         .chars()
         .filter_map(|c| match c {
-            '2'..='9' => Some(c.to_string().parse().unwrap()),
+            '2'..='9' => c.to_digit(10).map(u64::from),
             'T' => Some(10),
             'J' => Some(11),
             'Q' => Some(12),
@@ -90,14 +105,14 @@ fn value_hand(cards: &str, bid: &str) -> Result<Hand> {
 
     let score = get_score(&cards);
 
-    Ok(Hand { score, cards, bid: bid.parse().unwrap() })
+    Ok(Hand { score, cards, bid })
 }
 
-fn value_hand_2(cards: &str, bid: &str) -> Result<Hand> {
+fn value_hand_2(cards: &str, bid: u64) -> Result<Hand> {
     let cards = cards
         .chars()
         .filter_map(|c| match c {
-            '2'..='9' => Some(c.to_string().parse().unwrap()),
+            '2'..='9' => c.to_digit(10).map(u64::from),
             'T' => Some(10),
             'J' => Some(1),
             'Q' => Some(12),
@@ -114,35 +129,51 @@ fn value_hand_2(cards: &str, bid: &str) -> Result<Hand> {
         highest = max(highest, score);
     }
 
-    Ok(Hand { score: highest, cards, bid: bid.parse().unwrap() })
+    Ok(Hand { score: highest, cards, bid })
 }
 
 
-fn main() -> Result<()> {
-    let input = include_str!("../../inputs/input-07-2023.txt");
-    // let input = include_str!("../../inputs/test-07.txt");
+pub struct Day07;
 
-    let part_1_ans = part_one(input)?;
-    println!("Part One answer: {part_1_ans}");
+impl Solution for Day07 {
+    const DAY: u8 = 7;
 
-    let part_2_ans = part_two(input)?;
-    println!("Part Two answer: {part_2_ans}");
+    type Parsed = Vec<Play>;
+    type PartOne = u64;
+    type PartTwo = u64;
 
-    Ok(())
-}
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        parse::lines(input, |l| {
+            let Some((cards, bid)) = l.split_whitespace().collect_tuple() else {
+                return Err(error_at(l, "Expected a hand and a bid"));
+            };
+            if let Some(i) = cards.find(|c| !"23456789TJQKA".contains(c)) {
+                return Err(error_at(&cards[i..], "Bad card"));
+            }
+            if cards.len() != 5 {
+                return Err(error_at(cards, "Expected five cards"));
+            }
+            Ok(Play { cards, bid: parse_at(bid)? })
+        })
+    }
 
-fn part_one(input: &'static str) -> Result<u64> {
-    let hands = input.lines().map(|l| {
-        let (cards, bid) = l.split_whitespace().take(2).collect_tuple().unwrap();
-        value_hand(cards, bid).unwrap()
-    }).sorted().collect::<Vec<_>>();
-    Ok(hands.iter().enumerate().map(|(i, h)| (i + 1) as u64 * h.bid).sum())
-}
+    fn part_one(plays: &Self::Parsed) -> Result<u64> {
+        let hands = plays.iter()
+            .map(|p| value_hand(p.cards, p.bid))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .sorted()
+            .collect::<Vec<_>>();
+        Ok(hands.iter().enumerate().map(|(i, h)| (i + 1) as u64 * h.bid).sum())
+    }
 
-fn part_two(input: &'static str) -> Result<u64> {
-    let hands = input.lines().map(|l| {
-        let (cards, bid) = l.split_whitespace().take(2).collect_tuple().unwrap();
-        value_hand_2(cards, bid).unwrap()
-    }).sorted().collect::<Vec<_>>();
-    Ok(hands.iter().enumerate().map(|(i, h)| (i + 1) as u64 * h.bid).sum())
+    fn part_two(plays: &Self::Parsed) -> Result<u64> {
+        let hands = plays.iter()
+            .map(|p| value_hand_2(p.cards, p.bid))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .sorted()
+            .collect::<Vec<_>>();
+        Ok(hands.iter().enumerate().map(|(i, h)| (i + 1) as u64 * h.bid).sum())
+    }
 }
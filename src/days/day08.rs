@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use nom::character::complete::one_of;
+use nom::combinator::{map_res, recognize};
+use nom::multi::many1;
+use nom::sequence::preceded;
+use nom::IResult;
+use tracing::debug;
+
+use crate::parse::error_at;
+use crate::solution::Solution;
+use crate::utils::number::{crt, Congruence};
+use crate::utils::Cycle;
+use crate::utils::parsers::{blank_line, full, lines, pair_record};
+
+#[derive(Debug)]
+enum Dirs {
+    Left,
+    Right,
+}
+
+impl FromStr for Dirs {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Dirs> {
+        use Dirs::*;
+        match s {
+            "L" => Ok(Left),
+            "R" => Ok(Right),
+            _ => bail!("Bad input: {s}"),
+        }
+    }
+}
+
+pub struct Network {
+    moves: Vec<Dirs>,
+    graph: HashMap<&'static str, (&'static str, &'static str)>,
+}
+
+fn parse_network(input: &'static str) -> IResult<&'static str, Network> {
+    let (input, moves) = many1(map_res(recognize(one_of("LR")), Dirs::from_str))(input)?;
+    let (input, nodes) = preceded(blank_line, lines(pair_record))(input)?;
+    Ok((input, Network { moves, graph: nodes.into_iter().collect() }))
+}
+
+impl Network {
+    fn step(&self, node: &str, dir: &Dirs) -> &'static str {
+        let (left, right) = self.graph[node];
+        match dir {
+            Dirs::Left => left,
+            Dirs::Right => right,
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = Network;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        let network = full(parse_network)(input)?.1;
+        for (left, right) in network.graph.values() {
+            for dest in [left, right] {
+                if !network.graph.contains_key(dest) {
+                    return Err(error_at(dest, format!("No node {dest}")));
+                }
+            }
+        }
+        Ok(network)
+    }
+
+    fn part_one(network: &Self::Parsed) -> Result<u64> {
+        let mut res = 0;
+        let mut curr = &"AAA";
+
+        loop {
+            for dir in &network.moves {
+                res += 1;
+                let (left, right) = network.graph.get(curr).with_context(|| format!("No node {curr}"))?;
+                match dir {
+                    Dirs::Left => curr = left,
+                    Dirs::Right => curr = right,
+                }
+                if *curr == "ZZZ" {
+                    return Ok(res);
+                }
+            }
+        }
+    }
+
+    /// Each ghost walks into a loop of (node, move) states. Once they are all in their loops,
+    /// each is on a Z node at certain steps mod its loop length, which the CRT lines up.
+    fn part_two(network: &Self::Parsed) -> Result<u64> {
+        let mut starts = network.graph.keys().filter(|s| s.ends_with('A')).copied().collect::<Vec<_>>();
+        starts.sort();
+        if starts.is_empty() {
+            bail!("No start nodes");
+        }
+        let cycles = starts
+            .iter()
+            .map(|start| Cycle::of((*start, 0), |&(node, i)| (network.step(node, &network.moves[i]), (i + 1) % network.moves.len())))
+            .collect::<Vec<_>>();
+        let at_z = |cycle: &Cycle<(&str, usize)>, n: usize| cycle.nth(n).0.ends_with('Z');
+
+        // Before they are all looping, just check each step.
+        let settled = cycles.iter().map(|c| c.start).max().unwrap_or(0).max(1);
+        if let Some(n) = (1..settled).find(|n| cycles.iter().all(|c| at_z(c, *n))) {
+            return Ok(n as u64);
+        }
+
+        let residues = cycles.iter().map(|c| {
+            debug!("Loop of {} from step {}", c.length, c.start);
+            (c.start..c.states.len())
+                .filter(|n| at_z(c, *n))
+                .map(|n| Congruence::new(n as u64, c.length as u64))
+                .collect::<Vec<_>>()
+        });
+        let mut best = None;
+        for congruences in residues.multi_cartesian_product() {
+            if let Some(first) = crt(congruences)?.and_then(|c| c.first_from(settled as u64)) {
+                best = Some(best.map_or(first, |b: u64| b.min(first)));
+            }
+        }
+        best.context("The ghosts are never all on Z nodes at once")
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::Result;
+
+use crate::solution::Solution;
+use crate::utils::parsers::{full, lines, signed, spaced};
+
+fn compute_sequences(hist: &[i64]) -> Vec<Vec<i64>> {
+    let mut res = Vec::new();
+    let mut diffs = hist.to_vec();
+    loop {
+        diffs = get_diffs(&diffs);
+        if diffs.iter().all(|x| x == &0) {
+            break;
+        }
+        res.push(diffs.clone());
+    }
+    res
+}
+
+fn get_diffs(vec: &[i64]) -> Vec<i64> {
+    vec.windows(2).map(|x| x[1] - x[0]).collect()
+}
+
+fn compute_next(hist: &[i64]) -> i64 {
+    let seqs = compute_sequences(hist);
+    seqs.iter().map(|v| v.last().unwrap()).sum::<i64>() + hist.last().unwrap()
+}
+
+fn compute_prev(hist: &[i64]) -> i64 {
+    let seqs = compute_sequences(hist);
+    let mut acc = 0;
+    for seq in seqs.iter().rev() {
+        acc = seq.first().unwrap() - acc;
+    }
+    hist.first().unwrap() - acc
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Vec<i64>>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(full(lines(spaced(signed)))(input)?.1)
+    }
+
+    fn part_one(histories: &Self::Parsed) -> Result<i64> {
+        Ok(histories.iter().map(|h| compute_next(h)).sum())
+    }
+
+    fn part_two(histories: &Self::Parsed) -> Result<i64> {
+        Ok(histories.iter().map(|h| compute_prev(h)).sum())
+    }
+}
+
+/// A few short histories, negative values included.
+#[cfg(test)]
+pub(crate) mod tests {
+    use itertools::Itertools;
+
+    use crate::utils::rng::Rng;
+
+    pub fn histories(rng: &mut Rng) -> Vec<Vec<i64>> {
+        (0..rng.range(1..=5))
+            .map(|_| (0..rng.range(1..=8)).map(|_| rng.range(-1_000_000..=1_000_000)).collect())
+            .collect()
+    }
+
+    pub fn print(histories: &[Vec<i64>]) -> String {
+        histories.iter().map(|h| format!("{}\n", h.iter().join(" "))).collect()
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use anyhow::{bail, Result};
+use glam::IVec2;
+use tracing::{debug, Level};
+
+use crate::image::{Image, Rgb};
+use crate::parse::error_at;
+use crate::solution::Solution;
+use crate::utils::polygon::Polygon;
+use crate::utils::{Dir, Grid};
+
+use self::Pipe::{GROUND, START};
+
+type Point = IVec2;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Pipe {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    START,
+    GROUND,
+    X,
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        use Pipe::*;
+        Ok(match value {
+            '|' => NS,
+            '-' => EW,
+            'L' => NE,
+            'J' => NW,
+            '7' => SW,
+            'F' => SE,
+            '.' => GROUND,
+            'S' => START,
+            _ => bail!("Bad input {value}"),
+        })
+    }
+}
+
+impl fmt::Display for Pipe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Pipe::*;
+        let c = match self {
+            NS => '|',
+            EW => '-',
+            NE => 'L',
+            NW => 'J',
+            SW => '7',
+            SE => 'F',
+            START => 'S',
+            GROUND => '.',
+            X => 'X',
+        };
+        write!(f, "{c}")
+    }
+}
+
+/// The tiles the pipe at `point` connects to, or `None` for ground and points off the grid.
+fn find_adjacent(pipes: &Grid<Pipe>, point: &Point) -> Option<(Point, Point)> {
+    let p = *point;
+    use Dir::*;
+    Some(match find_beneath(pipes, point)? {
+        Pipe::NS => (p + North, p + South),
+        Pipe::EW => (p + West, p + East),
+        Pipe::NE => (p + North, p + East),
+        Pipe::NW => (p + North, p + West),
+        Pipe::SW => (p + South, p + West),
+        Pipe::SE => (p + South, p + East),
+        START | GROUND | Pipe::X => return None,
+    })
+}
+
+fn find_beneath(pipes: &Grid<Pipe>, loc: &Point) -> Option<Pipe> {
+    use Pipe::*;
+    let pipe = *pipes.get(*loc)?;
+    if pipe != START {
+        return Some(pipe);
+    }
+    let connects = |dir: Dir, pipes_from: [Pipe; 3]| {
+        pipes.get(*loc + dir).is_some_and(|p| pipes_from.contains(p))
+    };
+    let (north, south) = (connects(Dir::North, [SE, SW, NS]), connects(Dir::South, [NE, NW, NS]));
+    let (east, west) = (connects(Dir::East, [EW, NW, SW]), connects(Dir::West, [NE, EW, SE]));
+    if north {
+        if east {
+            return Some(NE);
+        }
+        if west {
+            return Some(NW);
+        }
+        return Some(NS);
+    }
+    if south {
+        if east {
+            return Some(SE);
+        }
+        if west {
+            return Some(SW);
+        }
+    }
+    Some(EW)
+}
+
+/// The loop's tiles in order from `start`, or the tile where it breaks off: where a pipe leads
+/// onto ground or off the grid, or into a pipe that doesn't connect back.
+fn trace_loop(pipes: &Grid<Pipe>, start: Point) -> std::result::Result<Vec<Point>, Point> {
+    let mut tiles = vec![start];
+    let (mut prev, mut curr) = (start, find_adjacent(pipes, &start).ok_or(start)?.0);
+    while curr != start {
+        let (a, b) = find_adjacent(pipes, &curr).ok_or(prev)?;
+        let next = match (a == prev, b == prev) {
+            (true, _) => b,
+            (_, true) => a,
+            _ => return Err(curr),
+        };
+        tiles.push(curr);
+        (prev, curr) = (curr, next);
+    }
+    match find_adjacent(pipes, &start) {
+        Some((a, b)) if a == prev || b == prev => Ok(tiles),
+        _ => Err(start),
+    }
+}
+
+/// The tiles either side of the `i`th tile along the loop.
+fn loop_neighbours(tiles: &[Point], i: usize) -> (Point, Point) {
+    (tiles[(i + tiles.len() - 1) % tiles.len()], tiles[(i + 1) % tiles.len()])
+}
+
+/// The polygon through the centres of the loop's corner tiles, where it doesn't carry straight
+/// on.
+fn loop_polygon(tiles: &[Point]) -> Polygon {
+    let corners = (0..tiles.len())
+        .filter(|i| {
+            let (prev, next) = loop_neighbours(tiles, *i);
+            tiles[*i] - prev != next - tiles[*i]
+        })
+        .map(|i| tiles[i].as_i64vec2())
+        .collect();
+    Polygon::new(corners)
+}
+
+/// The pipes, and the loop through the start tile traced out round them.
+#[derive(Debug)]
+pub struct Maze {
+    pipes: Grid<Pipe>,
+    tiles: Vec<Point>,
+}
+
+const OUTSIDE: Rgb = [28, 28, 44];
+const INSIDE: Rgb = [64, 150, 92];
+const LOOP: Rgb = [236, 184, 64];
+const START_TILE: Rgb = [226, 72, 60];
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Maze;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    /// Checks there is exactly one start tile and that the loop through it closes.
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        let pipes = Grid::parse(input, Pipe::try_from)?;
+        let at = |p: Point| input.lines().nth(p.y as usize).map_or(input, |line| &line[p.x as usize..]);
+        let starts = pipes.iter().filter(|(_, p)| **p == START).map(|(point, _)| point).collect::<Vec<_>>();
+        let start = match starts[..] {
+            [] => bail!("There is no start tile S"),
+            [start] => start,
+            [_, second, ..] => return Err(error_at(at(second), "Expected only one start tile S")),
+        };
+        let tiles = trace_loop(&pipes, start).map_err(|broken| error_at(at(broken), "The loop breaks off here"))?;
+        Ok(Maze { pipes, tiles })
+    }
+
+    /// Halfway round the loop in either direction.
+    fn part_one(maze: &Self::Parsed) -> Result<usize> {
+        Ok(maze.tiles.len() / 2)
+    }
+
+    /// The loop's tiles are the boundary points of the polygon through their centres, so the
+    /// enclosed tiles are its interior points.
+    fn part_two(maze: &Self::Parsed) -> Result<usize> {
+        let polygon = loop_polygon(&maze.tiles);
+
+        if tracing::enabled!(Level::DEBUG) {
+            let on_loop = maze.tiles.iter().collect::<HashSet<_>>();
+            let mut pipes = maze.pipes.clone();
+            for point in pipes.points() {
+                if !on_loop.contains(&point) {
+                    pipes[point] = if polygon.contains(point.as_i64vec2()) { Pipe::X } else { GROUND };
+                }
+            }
+            debug!("Enclosed tiles marked X:\n{pipes}");
+        }
+
+        Ok(polygon.interior_points() as usize)
+    }
+
+    /// The loop drawn as pipes three pixels to a tile, on shading that tells the enclosed tiles
+    /// from the rest. Pipes off the loop are left out.
+    fn render(maze: &Self::Parsed) -> Result<Image> {
+        let Maze { pipes, tiles } = maze;
+        let polygon = loop_polygon(tiles);
+        let mut image = Image::new(pipes.width() * 3, pipes.height() * 3, OUTSIDE);
+        for point in pipes.points() {
+            if polygon.contains(point.as_i64vec2()) {
+                image.fill(point * 3, IVec2::splat(3), INSIDE);
+            }
+        }
+        for (i, point) in tiles.iter().enumerate() {
+            let colour = if i == 0 { START_TILE } else { LOOP };
+            let centre = *point * 3 + IVec2::ONE;
+            let (prev, next) = loop_neighbours(tiles, i);
+            for pixel in [centre, centre + prev - *point, centre + next - *point] {
+                image.set(pixel, colour);
+            }
+        }
+        Ok(image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn broken_loops() {
+        let maze = Day10::parse("-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF").unwrap();
+        assert_eq!((Day10::part_one(&maze).unwrap(), Day10::part_two(&maze).unwrap()), (4, 1));
+
+        for (input, at) in [
+            ("...\n.F.\n...", "There is no start"),
+            (".S7\n.LJ\nS..", "line 3, column 1"),
+            // S reads as an L, and the pipe above it leads off the top of the grid.
+            (".|.\n.S7\n.LJ", "line 1, column 2"),
+            // The 7 doesn't connect back to the pipe leading into it.
+            ("S-7\n|.7\nL-J", "line 2, column 3"),
+            // The bottom leads onto ground.
+            ("S-7\n|.|\nL..", "line 3, column 1"),
+        ] {
+            let err = (days::get(10).unwrap().parse)(input).unwrap_err();
+            assert!(format!("{err:#}").contains(at), "{input:?}: {err:#}");
+        }
+    }
+}
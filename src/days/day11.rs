@@ -0,0 +1,66 @@
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::solution::Solution;
+use crate::utils::Grid;
+
+type Point = (usize, usize);
+
+pub struct Image {
+    galaxies: Vec<Point>,
+    empty_rows: Vec<usize>,
+    empty_cols: Vec<usize>,
+}
+
+fn parse_image(input: &'static str) -> Result<Image> {
+    let grid = Grid::parse(input, Ok)?;
+    let empty_cols = grid
+        .columns()
+        .positions(|mut col| col.all(|c| *c == '.'))
+        .collect_vec();
+    let empty_rows = grid
+        .rows()
+        .positions(|row| row.iter().all(|c| *c == '.'))
+        .collect_vec();
+    let galaxies = grid
+        .iter()
+        .filter(|(_, c)| **c == '#')
+        .map(|(p, _)| (p.y as usize, p.x as usize))
+        .collect::<Vec<Point>>();
+    Ok(Image { galaxies, empty_rows, empty_cols })
+}
+
+fn expansion(empties: &[usize], a: usize, b: usize, factor: usize) -> usize {
+    let range = if a < b { a..b } else { b..a };
+    range.filter(|x| empties.contains(x)).count() * (factor - 1)
+}
+
+fn distances(image: &Image, factor: usize) -> usize {
+    image.galaxies.iter().tuple_combinations::<(_, _)>().map(|(a, b)|{
+        a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+            + expansion(&image.empty_rows, a.0, b.0, factor)
+            + expansion(&image.empty_cols, a.1, b.1, factor)
+    }).sum::<usize>()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed = Image;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        parse_image(input)
+    }
+
+    fn part_one(image: &Self::Parsed) -> Result<usize> {
+        Ok(distances(image, 2))
+    }
+
+    fn part_two(image: &Self::Parsed) -> Result<usize> {
+        Ok(distances(image, 1_000_000))
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::{anyhow, Result};
+use tracing::trace;
+
+use crate::parallel;
+use crate::parse::{self, error_at, parse_at};
+use crate::solution::Solution;
+use crate::utils::memo::Memo;
+
+use self::Spring::Unknown;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Self::Damaged),
+            '.' => Ok(Self::Operational),
+            '?' => Ok(Self::Unknown),
+            _ => Err(anyhow!("Bad Value {value}"))
+        }
+    }
+}
+
+type Record = (Vec<Spring>, Vec<u64>);
+
+type Cache = Memo<(usize, usize, u64), u64>;
+
+fn parse_line(input: &'static str) -> Result<Record> {
+    let (springs, groups) = input
+        .split_once(' ')
+        .ok_or_else(|| error_at(input, "Expected springs and groups"))?;
+    let springs = springs
+        .char_indices()
+        .map(|(i, c)| Spring::try_from(c).map_err(|err| error_at(&springs[i..], err)))
+        .collect::<Result<Vec<Spring>>>()?;
+    Ok((springs, groups.split(',').map(parse_at).collect::<Result<Vec<u64>>>()?))
+}
+
+fn unfold((springs, groups): &Record) -> Record {
+    let mut unfolded = springs.clone();
+    for _ in 1..5 {
+        unfolded.push(Unknown);
+        unfolded.extend(springs);
+    }
+    (unfolded, groups.repeat(5))
+}
+
+
+/// Arrangements of `springs[spring_ptr..]` given `group_running` damaged springs just before it
+/// and the groups from `group_ptr` on still to place.
+fn count(memo: &mut Cache, springs: &[Spring], groups: &[u64], spring_ptr: usize, group_ptr: usize, group_running: u64) -> u64 {
+    memo.get((spring_ptr, group_ptr, group_running), |memo| {
+        if spring_ptr >= springs.len() {
+            if group_ptr < groups.len() - 1
+                || (group_ptr == groups.len() - 1 && group_running != groups[group_ptr])
+                || (group_ptr >= groups.len() && group_running > 0)
+            {
+                return 0;
+            }
+            return 1;
+        }
+        match springs[spring_ptr] {
+            Spring::Operational => {
+                count_operational(memo, springs, groups, spring_ptr, group_ptr, group_running)
+            }
+            Spring::Damaged => {
+                count(memo, springs, groups, spring_ptr + 1, group_ptr, group_running + 1)
+            }
+            Unknown => {
+                count_operational(memo, springs, groups, spring_ptr, group_ptr, group_running)
+                    + count(memo, springs, groups, spring_ptr + 1, group_ptr, group_running + 1)
+            }
+        }
+    })
+}
+
+fn count_operational(memo: &mut Cache, springs: &[Spring], groups: &[u64], spring_ptr: usize, group_ptr: usize, group_running: u64) -> u64 {
+    if group_running == 0 {
+        count(memo, springs, groups, spring_ptr + 1, group_ptr, group_running)
+    } else if group_ptr >= groups.len() || group_running != groups[group_ptr] {
+        0
+    } else {
+        count(memo, springs, groups, spring_ptr + 1, group_ptr + 1, 0)
+    }
+}
+
+fn arrangements((springs, groups): &Record) -> u64 {
+    let mut memo = Memo::new();
+    let res = count(&mut memo, springs, groups, 0, 0, 0);
+    trace!("{res} arrangements: {}", memo.stats());
+    res
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed = Vec<Record>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        parse::lines(input, parse_line)
+    }
+
+    fn part_one(records: &Self::Parsed) -> Result<u64> {
+        Ok(parallel::map(records, arrangements).into_iter().sum())
+    }
+
+    fn part_two(records: &Self::Parsed) -> Result<u64> {
+        Ok(parallel::map(records, |record| arrangements(&unfold(record))).into_iter().sum())
+    }
+}
+
+/// Records of up to 20 springs and five groups, which needn't have any arrangement since parsing
+/// doesn't look for one.
+#[cfg(test)]
+pub(crate) mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    pub fn records(rng: &mut Rng) -> Vec<Record> {
+        (0..rng.range(1..=5))
+            .map(|_| {
+                let springs = (0..rng.range(1..=20))
+                    .map(|_| *rng.pick(&[Spring::Operational, Spring::Damaged, Unknown]))
+                    .collect();
+                let groups = (0..rng.range(1..=5)).map(|_| rng.range(1..=6) as u64).collect();
+                (springs, groups)
+            })
+            .collect()
+    }
+
+    pub fn print(records: &[Record]) -> String {
+        records
+            .iter()
+            .map(|(springs, groups)| {
+                let springs = springs.iter().map(|s| match s {
+                    Spring::Operational => '.',
+                    Spring::Damaged => '#',
+                    Unknown => '?',
+                });
+                format!("{} {}\n", springs.collect::<String>(), groups.iter().join(","))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,81 @@
+use std::iter::zip;
+use anyhow::{bail, Result};
+
+use crate::solution::Solution;
+use crate::utils::parsers::{block, full, sections};
+use crate::utils::Grid;
+
+fn parse_patterns(input: &'static str) -> Result<Vec<Grid<char>>> {
+    let (_, patterns) = full(sections(block))(input)?;
+    patterns.into_iter().map(|pat| Grid::parse(pat, Ok)).collect()
+}
+
+fn horizontals(pat: &Grid<char>) -> usize {
+    (1..pat.height()).filter(|index|
+        zip(
+            (0..*index).rev(),
+            *index..pat.height()
+        ).all(|(a, b)| pat.row(a) == pat.row(b))
+    ).sum::<usize>()
+}
+
+fn horizontal_smudge(pat: &Grid<char>) -> usize {
+    for index in 1..pat.height() {
+        if zip(
+            (0..index).rev(),
+            index..pat.height()
+        ).map(|(a, b)|
+            zip(pat.row(a), pat.row(b)).filter(|(x, y)| x != y).count()
+        ).sum::<usize>() == 1 {
+            return index;
+        }
+    }
+    0
+}
+
+/// A vertical line of reflection is a horizontal one in the transposed pattern.
+fn verticals(pat: &Grid<char>) -> usize {
+    horizontals(&pat.transpose())
+}
+
+fn vertical_smudge(pat: &Grid<char>) -> usize {
+    horizontal_smudge(&pat.transpose())
+}
+
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed = Vec<Grid<char>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        parse_patterns(input)
+    }
+
+    fn part_one(patterns: &Self::Parsed) -> Result<usize> {
+        let verts = patterns.iter().map(verticals).sum::<usize>();
+        let horz = patterns.iter().map(horizontals).sum::<usize>();
+        Ok((horz*100) + verts)
+    }
+
+    fn part_two(patterns: &Self::Parsed) -> Result<usize> {
+        let mut res = 0;
+        for (i, pat) in patterns.iter().enumerate() {
+            let h = horizontal_smudge(pat);
+            if h > 0 {
+                res += h * 100;
+            } else {
+                let v = vertical_smudge(pat);
+                if v == 0 {
+                    bail!("Pattern {} has no line of reflection with exactly one smudge", i + 1);
+                }
+                res += v;
+            }
+        }
+        Ok(res)
+    }
+}
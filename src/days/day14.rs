@@ -0,0 +1,138 @@
+use std::fmt;
+
+use anyhow::{bail, Result};
+use glam::IVec2;
+use tracing::debug;
+
+use crate::animate;
+use crate::image::Rgb;
+use crate::solution::Solution;
+use crate::utils::{Cycle, Grid};
+
+use self::Rock::{Empty, Round, Square};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Rock {
+    Round,
+    Square,
+    Empty,
+}
+
+impl TryFrom<char> for Rock {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        Ok(match c {
+            'O' => Round,
+            '#' => Square,
+            '.' => Empty,
+            _ => bail!("Bad input {c}"),
+        })
+    }
+}
+
+impl Rock {
+    fn symbol(self) -> char {
+        match self {
+            Round => 'O',
+            Square => '#',
+            Empty => '.',
+        }
+    }
+}
+
+impl fmt::Display for Rock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+type Map = Grid<Rock>;
+
+fn roll_north(map: &mut Map) {
+    for x in 0..map.width() as i32 {
+        let mut stop = 0;
+        for y in 0..map.height() as i32 {
+            match map[IVec2::new(x, y)] {
+                Square => stop = y + 1,
+                Round => {
+                    map[IVec2::new(x, y)] = Empty;
+                    map[IVec2::new(x, stop)] = Round;
+                    stop += 1;
+                }
+                Empty => {}
+            }
+        }
+    }
+}
+
+const ROUND_ROCK: Rgb = [236, 184, 64];
+const SQUARE_ROCK: Rgb = [120, 120, 140];
+const FLOOR: Rgb = [60, 60, 72];
+
+/// Shows the map after a tilt towards `edge`, `turns` quarter turns clockwise from how it reads.
+fn show(map: &Map, edge: &str, turns: usize) {
+    animate::frame(&format!("Day 14, tilted {edge}"), || {
+        let mut map = map.clone();
+        for _ in 0..(4 - turns) % 4 {
+            map = map.rotate_clockwise();
+        }
+        animate::paint_grid(&map, |_, rock| {
+            let colour = match rock {
+                Round => ROUND_ROCK,
+                Square => SQUARE_ROCK,
+                Empty => FLOOR,
+            };
+            (rock.symbol(), colour)
+        })
+    });
+}
+
+/// Rolls north, west, south and east in turn. Each quarter turn clockwise brings the next of
+/// those edges to the top, and the fourth brings the map back to its original orientation.
+fn spin(map: &Map) -> Map {
+    let mut map = map.clone();
+    for (turns, edge) in ["north", "west", "south", "east"].into_iter().enumerate() {
+        roll_north(&mut map);
+        show(&map, edge, turns);
+        map = map.rotate_clockwise();
+    }
+    map
+}
+
+fn load(map: &Map) -> i64 {
+    map.iter()
+        .filter_map(|(p, r)| match r {
+            Round => Some(map.height() as i64 - p.y as i64),
+            _ => None,
+        })
+        .sum::<i64>()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Parsed = Map;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Grid::parse(input, Rock::try_from)
+    }
+
+    fn part_one(map: &Self::Parsed) -> Result<i64> {
+        let mut map = map.clone();
+        roll_north(&mut map);
+        show(&map, "north", 0);
+        debug!("Tilted north:\n{map}");
+        Ok(load(&map))
+    }
+
+    fn part_two(map: &Self::Parsed) -> Result<i64> {
+        let cycle = Cycle::of(map.clone(), spin);
+        debug!("Spins repeat every {} after {}", cycle.length, cycle.start);
+        Ok(load(cycle.nth(1_000_000_000)))
+    }
+}
@@ -0,0 +1,81 @@
+use std::ops::Rem;
+
+use anyhow::Result;
+
+use crate::parse::{error_at, parse_at};
+use crate::solution::Solution;
+
+#[derive(Copy, Clone, Debug)]
+struct Lens {
+    label: &'static str,
+    focal: u64,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Op {
+    Remove,
+    Insert(u64),
+}
+
+#[derive(Debug)]
+pub struct Step {
+    text: &'static str,
+    label: &'static str,
+    op: Op,
+}
+
+fn parse_step(text: &'static str) -> Result<Step> {
+    if let Some(label) = text.strip_suffix('-') {
+        Ok(Step { text, label, op: Op::Remove })
+    } else {
+        let Some((label, focal)) = text.split_once('=') else {
+            return Err(error_at(text, "Expected `label-` or `label=focal`"));
+        };
+        Ok(Step { text, label, op: Op::Insert(parse_at(focal)?) })
+    }
+}
+
+fn hash(input: &str) -> u64 {
+    input.chars().map(|c| c as u64).fold(0, |acc, x| { ((acc + x) * 17).rem(256) })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed = Vec<Step>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        input.trim().split(',').map(parse_step).collect()
+    }
+
+    fn part_one(steps: &Self::Parsed) -> Result<u64> {
+        Ok(steps.iter().map(|s| hash(s.text)).sum())
+    }
+
+    fn part_two(steps: &Self::Parsed) -> Result<u64> {
+        let mut hashmap: Vec<Vec<Lens>> = (0..256).map(|_| Vec::new()).collect();
+        for step in steps {
+            let label = step.label;
+            let v = &mut hashmap[hash(label) as usize];
+            match step.op {
+                Op::Remove => v.retain(|l| l.label != label),
+                Op::Insert(focal) => {
+                    if let Some(l) = v.iter_mut().find(|l| l.label == label) {
+                        l.focal = focal;
+                    } else {
+                        v.push(Lens { label, focal })
+                    }
+                }
+            }
+        }
+        let res = hashmap.iter().enumerate().flat_map(|(num, v)|{
+            v.iter().enumerate().map(move |(i, l)| (num + 1) * (i + 1) * (l.focal as usize))
+        }).sum::<usize>() as u64;
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use glam::IVec2;
+use itertools::Itertools;
+
+use crate::animate;
+use crate::image::{shade, Image, Rgb};
+use crate::parallel;
+use crate::solution::Solution;
+use crate::utils::{Dir, Grid, Mirror};
+
+use self::Tile::{Empty, Splitter};
+
+#[derive(Debug, Copy, Clone)]
+pub enum Splitters {
+    Horizontal,
+    // "-"
+    Vertical, // "|"
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Tile {
+    Empty,
+    Splitter(Splitters),
+    Mirror(Mirror),
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        Ok(match c {
+            '.' => Empty,
+            '-' => Splitter(Splitters::Horizontal),
+            '|' => Splitter(Splitters::Vertical),
+            '/' | '\\' => Tile::Mirror(c.try_into()?),
+            _ => bail!("Bad input {c}"),
+        })
+    }
+}
+
+impl Tile {
+    fn symbol(self) -> char {
+        match self {
+            Empty => '.',
+            Splitter(Splitters::Horizontal) => '-',
+            Splitter(Splitters::Vertical) => '|',
+            Tile::Mirror(Mirror::Slash) => '/',
+            Tile::Mirror(Mirror::Backslash) => '\\',
+        }
+    }
+}
+
+type Point = IVec2;
+
+type Map = Grid<Tile>;
+
+fn simulate_beam(map: &Map, start: &Point, dir: Dir, energized: &mut HashSet<(Point, Dir)>) {
+    let mut curr: Point = *start;
+    let mut dir = dir;
+    loop {
+        let next_point: Point = curr + dir;
+        let Some(tile) = map.get(next_point) else {
+            return;
+        };
+        if energized.contains(&(next_point, dir)) {
+            return;
+        }
+
+        energized.insert((next_point, dir));
+        animate::frame("Day 16, beam", || draw_beam(map, energized, next_point));
+
+        match tile {
+            Empty => {}
+            Splitter(Splitters::Vertical) => {
+                if !dir.is_vertical() {
+                    simulate_beam(map, &next_point, Dir::North, energized);
+                    simulate_beam(map, &next_point, Dir::South, energized);
+                    return;
+                }
+            }
+            Splitter(Splitters::Horizontal) => {
+                if dir.is_vertical() {
+                    simulate_beam(map, &next_point, Dir::East, energized);
+                    simulate_beam(map, &next_point, Dir::West, energized);
+                    return;
+                }
+            }
+            Tile::Mirror(mirror) => dir = dir.reflect(*mirror),
+        }
+        curr = next_point;
+    }
+}
+
+/// The map with the beam so far, ending at `head`. Empty tiles it crossed show its direction,
+/// or how many directions crossed them, the way the puzzle draws them.
+fn draw_beam(map: &Map, energized: &HashSet<(Point, Dir)>, head: Point) -> String {
+    animate::paint_grid(map, |point, tile| {
+        let dirs = Dir::ALL.into_iter().filter(|d| energized.contains(&(point, *d))).collect::<Vec<_>>();
+        let symbol = match (tile, &dirs[..]) {
+            (Empty, [Dir::North]) => '^',
+            (Empty, [Dir::East]) => '>',
+            (Empty, [Dir::South]) => 'v',
+            (Empty, [Dir::West]) => '<',
+            (Empty, [_, ..]) => char::from_digit(dirs.len() as u32, 10).unwrap_or('#'),
+            (tile, _) => tile.symbol(),
+        };
+        let colour = match (tile, dirs.is_empty()) {
+            _ if point == head => HEAD,
+            (Empty, true) => FLOOR,
+            (Empty, false) => BEAM,
+            (_, true) => OPTICS,
+            (_, false) => LIT_OPTICS,
+        };
+        (symbol, colour)
+    })
+}
+
+fn count_energized(map: &Map, start: &Point, dir: Dir) -> usize{
+    let mut energized = Default::default();
+    simulate_beam(map, start, dir, &mut energized);
+    energized.iter().map(|(p, _)| p).unique().count()
+}
+
+const DARK: Rgb = [20, 20, 28];
+const OPTICS: Rgb = [110, 110, 130];
+const LIT_OPTICS: Rgb = [255, 255, 255];
+const BEAM: Rgb = [255, 196, 40];
+const FLOOR: Rgb = [70, 70, 84];
+const HEAD: Rgb = [255, 64, 64];
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Parsed = Map;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Grid::parse(input, Tile::try_from)
+    }
+
+    fn part_one(map: &Self::Parsed) -> Result<usize> {
+        Ok(count_energized(map, &Point::new(-1, 0), Dir::East))
+    }
+
+    fn part_two(map: &Self::Parsed) -> Result<usize> {
+        let (width, height) = (map.width() as i32, map.height() as i32);
+        let starts = (0..width)
+            .flat_map(|x| [(Point::new(x, -1), Dir::South), (Point::new(x, height), Dir::North)])
+            .chain((0..height).flat_map(|y| [(Point::new(-1, y), Dir::East), (Point::new(width, y), Dir::West)]))
+            .collect::<Vec<_>>();
+        let energized = parallel::map(&starts, |(p, dir)| count_energized(map, p, *dir));
+        Ok(energized.into_iter().max().unwrap_or(0))
+    }
+
+    /// Part one's beam: energized tiles glow brighter the more directions light crosses them in,
+    /// and the mirrors and splitters show up light or grey by whether the beam reaches them.
+    fn render(map: &Self::Parsed) -> Result<Image> {
+        let mut energized = HashSet::new();
+        simulate_beam(map, &Point::new(-1, 0), Dir::East, &mut energized);
+        let mut crossings = HashMap::<Point, usize>::new();
+        for (point, _) in &energized {
+            *crossings.entry(*point).or_default() += 1;
+        }
+        Ok(Image::from_grid(map, |point, tile| match (tile, crossings.get(&point)) {
+            (Empty, None) => DARK,
+            (Empty, Some(n)) => shade(BEAM, 0.4 + 0.15 * *n as f64),
+            (_, None) => OPTICS,
+            (_, Some(_)) => LIT_OPTICS,
+        }))
+    }
+}
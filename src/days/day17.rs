@@ -0,0 +1,101 @@
+use anyhow::{Context, Result};
+use glam::IVec2;
+use tracing::debug;
+
+use crate::image::{shade, Image, Rgb};
+use crate::solution::Solution;
+use crate::utils::search::{astar, Found};
+use crate::utils::{Dir, Grid};
+
+type Point = IVec2;
+
+type Map = Grid<i32>;
+
+fn manhattan(p1: &Point, p2: &Point) -> i32 {
+    (p1.x.abs_diff(p2.x) + p1.y.abs_diff(p2.y)) as i32
+}
+
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+struct Step {
+    point: Point,
+    dir: Dir,
+    steps: i32,
+}
+
+impl Step {
+    fn next(&self, map: &Map, min_steps: i32, max_steps: i32) -> Vec<(Step, i32)> {
+        let mut neighbors = Vec::new();
+        if self.steps < max_steps && map.contains(self.point + self.dir) {
+            neighbors.push(Step {
+                point: self.point + self.dir,
+                dir: self.dir,
+                steps: self.steps + 1,
+            })
+        }
+        if self.steps >= min_steps {
+            for turn in [self.dir.turn_left(), self.dir.turn_right()] {
+                if map.contains(self.point + turn) {
+                    neighbors.push(Step {
+                        point: self.point + turn,
+                        dir: turn,
+                        steps: 1,
+                    })
+                }
+            }
+        }
+        neighbors.into_iter().map(|n| {
+            let cost = map[n.point];
+            (n, cost)
+        }).collect()
+    }
+}
+
+
+fn find_least(map: &Map, start: Point, goal: Point, min_steps: i32, max_steps: i32, can_stop: bool) -> Result<Found<Step, i32>> {
+    let starts = [Dir::East, Dir::South].map(|dir| Step { point: start, dir, steps: 0 });
+    let found = astar(
+        starts,
+        |step| step.next(map, min_steps, max_steps),
+        |step| manhattan(&step.point, &goal),
+        |step| step.point == goal && (can_stop || step.steps >= min_steps),
+    )
+    .context("No path")?;
+    debug!(cost = found.cost, visited = found.visited, "found path");
+    Ok(found)
+}
+
+const HEAT: Rgb = [255, 120, 40];
+const PATH: Rgb = [90, 200, 255];
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Parsed = Map;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as i32).context("not a digit"))
+    }
+
+    fn part_one(map: &Self::Parsed) -> Result<i32> {
+        Ok(find_least(map, Point::new(0, 0), Point::new(map.width() as i32 - 1, map.height() as i32 - 1), 0, 3, true)?.cost)
+    }
+
+    fn part_two(map: &Self::Parsed) -> Result<i32> {
+        Ok(find_least(map, Point::new(0, 0), Point::new(map.width() as i32 - 1, map.height() as i32 - 1), 4, 10, false)?.cost)
+    }
+
+    /// The heat loss of each block as brightness, with part one's path drawn over it.
+    fn render(map: &Self::Parsed) -> Result<Image> {
+        let found = find_least(map, Point::new(0, 0), Point::new(map.width() as i32 - 1, map.height() as i32 - 1), 0, 3, true)?;
+        let mut image = Image::from_grid(map, |_, loss| shade(HEAT, *loss as f64 / 9.0));
+        for step in &found.path {
+            image.set(step.point, PATH);
+        }
+        Ok(image)
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use glam::I64Vec2;
+use nom::character::complete::{one_of, space1};
+use nom::combinator::map_res;
+use nom::sequence::separated_pair;
+
+use crate::image::{Image, Rgb};
+use crate::parse::{self, error_at};
+use crate::solution::Solution;
+use crate::utils::parsers::{full, unsigned};
+use crate::utils::polygon::Polygon;
+use crate::utils::Dir;
+
+type Point = I64Vec2;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Instruction {
+    dir: Dir,
+    count: i64,
+}
+
+/// `R 6`, the plan as it reads.
+fn parse_inst(input: &'static str) -> Result<Instruction> {
+    let (_, (dir, count)) = full(separated_pair(
+        map_res(one_of("RUDL"), Dir::try_from),
+        space1,
+        unsigned,
+    ))(input)?;
+    Ok(Instruction { dir, count, })
+}
+
+/// The directions a hex instruction's last digit, 0 to 3, stands for.
+const HEX_DIRS: [Dir; 4] = [Dir::East, Dir::South, Dir::West, Dir::North];
+
+/// `70c710`, the colour that is really a distance and a direction.
+fn parse_inst_hex(input: &'static str) -> Result<Instruction> {
+    if input.len() != 6 || !input.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(error_at(input, "Expected six hex digits"));
+    }
+    let (length, dir) = input.split_at(5);
+    let count = i64::from_str_radix(length, 16).map_err(|err| error_at(length, err))?;
+    let dir = match dir.parse::<usize>().ok().and_then(|d| HEX_DIRS.get(d)) {
+        Some(dir) => *dir,
+        None => return Err(error_at(dir, "Bad direction")),
+    };
+    Ok(Instruction { dir, count})
+}
+
+fn parse_line(line: &'static str) -> Result<(Instruction, Instruction)> {
+    let (inst, colour) = line
+        .split_once(" (#")
+        .ok_or_else(|| error_at(line, "Expected a `(#` colour"))?;
+    let colour = colour
+        .strip_suffix(')')
+        .ok_or_else(|| error_at(colour, "Expected a `)` after the colour"))?;
+    Ok((parse_inst(inst)?, parse_inst_hex(colour)?))
+}
+
+/// Every cube dug: the trench itself plus everything it encloses.
+fn lagoon(instructions: &[Instruction]) -> u64 {
+    let trench = Polygon::from_steps(
+        Point::ZERO,
+        instructions.iter().map(|inst| (Point::from(inst.dir), inst.count)),
+    );
+    (trench.interior_points() + trench.boundary_points()) as u64
+}
+
+/// The colour the hex instruction was written as, before it was read as a distance.
+fn colour(hex: &Instruction) -> Rgb {
+    let digit = HEX_DIRS.iter().position(|d| *d == hex.dir).unwrap_or(0);
+    let value = hex.count << 4 | digit as i64;
+    [(value >> 16) as u8, (value >> 8) as u8, value as u8]
+}
+
+const GROUND: Rgb = [40, 30, 24];
+const LAVA: Rgb = [120, 60, 30];
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Plan {
+    dig: Vec<Instruction>,
+    hex: Vec<Instruction>,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Parsed = Plan;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        let (dig, hex) = parse::lines(input, parse_line)?.into_iter().unzip();
+        Ok(Plan { dig, hex })
+    }
+
+    fn part_one(plan: &Self::Parsed) -> Result<u64> {
+        Ok(lagoon(&plan.dig))
+    }
+
+    fn part_two(plan: &Self::Parsed) -> Result<u64> {
+        Ok(lagoon(&plan.hex))
+    }
+
+    /// Part one's lagoon, with each stretch of trench painted the colour from its line of the plan.
+    fn render(plan: &Self::Parsed) -> Result<Image> {
+        let mut trench = HashMap::new();
+        let mut point = Point::ZERO;
+        for (dig, hex) in plan.dig.iter().zip(&plan.hex) {
+            for _ in 0..dig.count {
+                point += Point::from(dig.dir);
+                trench.insert(point, colour(hex));
+            }
+        }
+        let polygon = Polygon::from_steps(
+            Point::ZERO,
+            plan.dig.iter().map(|inst| (Point::from(inst.dir), inst.count)),
+        );
+        let min = trench.keys().fold(Point::ZERO, |min, p| min.min(*p));
+        let max = trench.keys().fold(Point::ZERO, |max, p| max.max(*p));
+        let size = max - min + Point::ONE;
+        let mut image = Image::new(size.x as usize, size.y as usize, GROUND);
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let point = Point::new(x, y);
+                let pixel = (point - min).as_ivec2();
+                match trench.get(&point) {
+                    Some(colour) => image.set(pixel, *colour),
+                    None if polygon.contains(point) => image.set(pixel, LAVA),
+                    None => {}
+                }
+            }
+        }
+        Ok(image)
+    }
+}
+
+/// Plans whose hex instructions have nothing to do with the dig ones, as the parser reads each
+/// on its own.
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    pub fn plan(rng: &mut Rng) -> Plan {
+        let (dig, hex) = (0..rng.range(1..=6))
+            .map(|_| {
+                (
+                    Instruction { dir: *rng.pick(&Dir::ALL), count: rng.range(1..=20) },
+                    Instruction { dir: *rng.pick(&Dir::ALL), count: rng.range(0..=0xfffff) },
+                )
+            })
+            .unzip();
+        Plan { dig, hex }
+    }
+
+    pub fn print(plan: &Plan) -> String {
+        plan.dig
+            .iter()
+            .zip(&plan.hex)
+            .map(|(dig, hex)| {
+                let dir = match dig.dir {
+                    Dir::North => 'U',
+                    Dir::East => 'R',
+                    Dir::South => 'D',
+                    Dir::West => 'L',
+                };
+                format!("{dir} {} (#{:05x}{})\n", dig.count, hex.count, HEX_DIRS.iter().position(|d| *d == hex.dir).unwrap())
+            })
+            .collect()
+    }
+}
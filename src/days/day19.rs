@@ -0,0 +1,384 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, bail, Result};
+use nom::IResult;
+use nom::bytes::complete::tag;
+use nom::branch::alt;
+use nom::character::complete::{alpha1, char, one_of};
+use nom::combinator::{map, map_res, value};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, preceded, separated_pair, tuple};
+use tracing::{debug, trace};
+
+use crate::parse::{self, error_at};
+use crate::solution::Solution;
+use crate::utils::interval::Interval;
+use crate::utils::parsers::{blank_line, block, full, unsigned};
+
+use self::Rule::IfJump;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Part {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Property {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<char> for Property {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            'x' => Ok(Property::X),
+            'm' => Ok(Property::M),
+            'a' => Ok(Property::A),
+            's' => Ok(Property::S),
+            _ => Err(anyhow!("Bad property"))
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Op {
+    LT,
+    GT,
+    Any,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Rule {
+    IfJump(Property, Op, u64, &'static str),
+    A,
+    R,
+}
+
+/// `a<2006:qkq`, or a bare destination to fall back to.
+fn parse_rule(input: &'static str) -> IResult<&'static str, Rule> {
+    alt((
+        map(
+            tuple((
+                map_res(one_of("xmas"), Property::try_from),
+                alt((value(Op::LT, char('<')), value(Op::GT, char('>')))),
+                unsigned,
+                preceded(char(':'), alpha1),
+            )),
+            |(property, op, num, dest)| IfJump(property, op, num, dest),
+        ),
+        map(alpha1, |dest| match dest {
+            "A" => Rule::A,
+            "R" => Rule::R,
+            _ => IfJump(Property::A, Op::Any, 0, dest),
+        }),
+    ))(input)
+}
+
+/// `px{a<2006:qkq,m>2090:A,rfg}`
+fn parse_workflow(input: &'static str) -> IResult<&'static str, (&'static str, Vec<Rule>)> {
+    pair(alpha1, delimited(char('{'), separated_list1(char(','), parse_rule), char('}')))(input)
+}
+
+/// The workflows by name. Names must be unique, every destination must be `A`, `R` or another
+/// workflow, each workflow must end in a rule that always applies, and no part can be sent
+/// round in a loop.
+fn parse_map(input: &'static str) -> Result<HashMap<&'static str, Vec<Rule>>> {
+    let workflows = parse::lines(input, |line| Ok(full(parse_workflow)(line)?.1))?;
+    let mut map = HashMap::new();
+    for (name, rules) in &workflows {
+        if map.insert(*name, rules.clone()).is_some() {
+            return Err(error_at(name, format!("There is already a workflow named {name}")));
+        }
+    }
+    if !map.contains_key("in") {
+        bail!("There is no `in` workflow");
+    }
+    for (name, rules) in &workflows {
+        for (i, rule) in rules.iter().enumerate() {
+            if let IfJump(_, op, _, dest) = rule {
+                if i + 1 == rules.len() && *op != Op::Any {
+                    return Err(error_at(dest, format!("The last rule of {name} has a condition")));
+                }
+                if !matches!(*dest, "A" | "R") && !map.contains_key(dest) {
+                    return Err(error_at(dest, format!("There is no workflow named {dest}")));
+                }
+            }
+        }
+    }
+    let mut done = HashSet::new();
+    for (name, _) in &workflows {
+        check_acyclic(&map, name, &mut Vec::new(), &mut done)?;
+    }
+    Ok(map)
+}
+
+/// Fails if a part could be sent from `name` back to a workflow on `path`, the workflows that
+/// led to it. `done` holds the workflows already known not to loop.
+fn check_acyclic(
+    map: &HashMap<&'static str, Vec<Rule>>,
+    name: &'static str,
+    path: &mut Vec<&'static str>,
+    done: &mut HashSet<&'static str>,
+) -> Result<()> {
+    if done.contains(name) {
+        return Ok(());
+    }
+    path.push(name);
+    for rule in &map[name] {
+        let IfJump(_, _, _, dest) = *rule else { continue };
+        if path.contains(&dest) {
+            return Err(error_at(dest, format!("Sending parts from {name} to {dest} makes a loop")));
+        }
+        if map.contains_key(dest) {
+            check_acyclic(map, dest, path, done)?;
+        }
+    }
+    path.pop();
+    done.insert(name);
+    Ok(())
+}
+
+/// `{x=787,m=2655,a=1222,s=2876}`
+fn parse_part(input: &'static str) -> IResult<&'static str, Part> {
+    let (input, (x, m, a, s)) = delimited(
+        tag("{x="),
+        tuple((
+            unsigned,
+            preceded(tag(",m="), unsigned),
+            preceded(tag(",a="), unsigned),
+            preceded(tag(",s="), unsigned),
+        )),
+        char('}'),
+    )(input)?;
+    Ok((input, Part { x, m, a, s }))
+}
+
+fn parse_parts(input: &'static str) -> Result<Vec<Part>> {
+    parse::lines(input, |line| Ok(full(parse_part)(line)?.1))
+}
+
+/// The ranges of x, m, a and s ratings still in play, indexed by [`Property`].
+type Ratings = [Interval; 4];
+
+fn combinations(ratings: &Ratings) -> u64 {
+    ratings.iter().map(Interval::len).product()
+}
+
+fn count_accepted(rules_map: &HashMap<&str, Vec<Rule>>, workflow: &str, index: usize, ratings: Ratings) -> u64 {
+    if ratings.iter().any(Interval::is_empty) {
+        return 0;
+    }
+    let rule = match workflow {
+        "A" => return combinations(&ratings),
+        "R" => return 0,
+        _ => rules_map[workflow][index],
+    };
+
+    match rule {
+        IfJump(_, Op::Any, _, dest) => count_accepted(rules_map, dest, 0, ratings),
+        IfJump(prop, op, num, dest) => {
+            let i = prop as usize;
+            let num = num as i64;
+            let (matching, rest) = match op {
+                Op::LT => ratings[i].split_at(num),
+                _ => {
+                    let (rest, matching) = ratings[i].split_at(num + 1);
+                    (matching, rest)
+                }
+            };
+            trace!("{workflow} rule {index} splits {:?} into {matching:?} and {rest:?}", ratings[i]);
+            let mut jumped = ratings;
+            jumped[i] = matching;
+            let mut next = ratings;
+            next[i] = rest;
+            count_accepted(rules_map, dest, 0, jumped) + count_accepted(rules_map, workflow, index + 1, next)
+        }
+        Rule::A => combinations(&ratings),
+        Rule::R => 0,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct System {
+    workflows: HashMap<&'static str, Vec<Rule>>,
+    parts: Vec<Part>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Parsed = System;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        let (_, (rules_text, parts_text)) = full(separated_pair(block, blank_line, block))(input)?;
+        Ok(System {
+            workflows: parse_map(rules_text)?,
+            parts: parse_parts(parts_text)?,
+        })
+    }
+
+    fn part_one(system: &Self::Parsed) -> Result<u64> {
+        let start = "in";
+        let rules_map = &system.workflows;
+
+        for (key, rules) in rules_map.iter() {
+            debug!("\"{key}\":  {rules:?}");
+        }
+
+        let mut res = 0;
+
+
+        'part: for part in &system.parts {
+            trace!("Part: {part:?}");
+            let mut rules = &rules_map[start];
+            let mut index = 0;
+            while let Rule::IfJump(prop, op, num, dest) = rules[index] {
+                let val = match prop {
+                    Property::X => { part.x }
+                    Property::M => { part.m }
+                    Property::A => { part.a }
+                    Property::S => { part.s }
+                };
+                if match op {
+                    Op::LT => { val < num }
+                    Op::GT => { val > num }
+                    Op::Any => { true }
+                } {
+                    match dest {
+                        "A" => {
+                            trace!("Accepted part {part:?}");
+                            res += part.x + part.m + part.a + part.s;
+                            continue 'part;
+                        }
+                        "R" => { continue 'part; }
+                        v => {
+                            trace!("Jumping to {v}");
+                            rules = &rules_map[v];
+                            index = 0;
+                        }
+                    }
+                } else {
+                    index += 1;
+                }
+            }
+            match rules[index] {
+                Rule::IfJump(_, _, _, _) => { unreachable!("Bad loop exit") }
+                Rule::A => {
+                    trace!("Accepted part {part:?}");
+                    res += part.x + part.m + part.a + part.s;
+                }
+                Rule::R => { continue; }
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn part_two(system: &Self::Parsed) -> Result<u64> {
+        let start = "in";
+        Ok(count_accepted(&system.workflows, start, 0, [Interval::from(1..=4000); 4]))
+    }
+}
+
+/// Systems whose workflows start at `in` and only send parts on to later ones, so they parse.
+#[cfg(test)]
+pub(crate) mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    fn name(rng: &mut Rng) -> &'static str {
+        (0..rng.range(2..=3)).map(|_| (b'a' + rng.range(0..=25) as u8) as char).collect::<String>().leak()
+    }
+
+    /// A rule sending parts to `A`, `R` or one of `later`.
+    fn rule(rng: &mut Rng, later: &[&'static str]) -> Rule {
+        let dest = if later.is_empty() || rng.chance(0.3) { *rng.pick(&["A", "R"]) } else { *rng.pick(later) };
+        IfJump(
+            *rng.pick(&[Property::X, Property::M, Property::A, Property::S]),
+            *rng.pick(&[Op::LT, Op::GT]),
+            rng.range(1..=4000) as u64,
+            dest,
+        )
+    }
+
+    pub fn system(rng: &mut Rng) -> System {
+        // Workflows only send parts on to later ones, so there are no loops.
+        let names = ["in"]
+            .into_iter()
+            .chain((0..rng.range(0..=4)).map(|_| name(rng)))
+            .unique()
+            .collect::<Vec<_>>();
+        let workflows = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let later = &names[i + 1..];
+                let mut rules = (0..rng.range(0..=3)).map(|_| rule(rng, later)).collect::<Vec<_>>();
+                rules.push(match rng.index(3) {
+                    0 => Rule::A,
+                    1 => Rule::R,
+                    _ if later.is_empty() => Rule::R,
+                    _ => IfJump(Property::A, Op::Any, 0, later[rng.index(later.len())]),
+                });
+                (*name, rules)
+            })
+            .collect();
+        let parts = (0..rng.range(1..=4))
+            .map(|_| {
+                let mut rating = || rng.range(1..=4000) as u64;
+                Part { x: rating(), m: rating(), a: rating(), s: rating() }
+            })
+            .collect();
+        System { workflows, parts }
+    }
+
+    pub fn print(system: &System) -> String {
+        let rule = |rule: &Rule| match rule {
+            IfJump(_, Op::Any, _, dest) => dest.to_string(),
+            IfJump(prop, op, num, dest) => {
+                let op = if *op == Op::LT { '<' } else { '>' };
+                format!("{}{op}{num}:{dest}", format!("{prop:?}").to_lowercase())
+            }
+            Rule::A => "A".to_string(),
+            Rule::R => "R".to_string(),
+        };
+        let workflows = system
+            .workflows
+            .iter()
+            .map(|(name, rules)| format!("{name}{{{}}}\n", rules.iter().map(rule).join(",")));
+        let parts = system
+            .parts
+            .iter()
+            .map(|p| format!("{{x={},m={},a={},s={}}}\n", p.x, p.m, p.a, p.s));
+        format!("{}\n{}", workflows.collect::<String>(), parts.collect::<String>())
+    }
+
+    #[test]
+    fn broken_workflows() {
+        for (workflows, problem) in [
+            ("in{x<5:ab,A}", "There is no workflow named ab"),
+            ("ab{A}", "There is no `in` workflow"),
+            ("in{ab}\nab{R}\nab{A}", "There is already a workflow named ab"),
+            ("in{ab}\nab{x>5:A,m<3:R}", "The last rule of ab has a condition"),
+            ("in{ab}\nab{x>5:cd,R}\ncd{a<9:in,A}", "Sending parts from cd to in makes a loop"),
+            ("in{in}", "Sending parts from in to in makes a loop"),
+        ] {
+            let input = format!("{workflows}\n\n{{x=1,m=2,a=3,s=4}}").leak();
+            let err = Day19::parse(input).unwrap_err();
+            assert_eq!(err.to_string(), problem, "{workflows}");
+        }
+    }
+}
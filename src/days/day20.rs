@@ -0,0 +1,332 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::{bail, Context, Result};
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, one_of};
+use nom::combinator::opt;
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, tuple};
+use tracing::{debug, trace};
+
+use crate::animate::{self, paint};
+use crate::image::Rgb;
+use crate::parse::error_at;
+use crate::solution::Solution;
+use crate::utils::cycle::period;
+use crate::utils::number::{crt, Congruence};
+use crate::utils::parsers::full;
+
+use self::Module::{Broadcast, Conjunction, Flip};
+
+const MAX_PRESSES: u64 = 100_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Module {
+    Flip(bool, Vec<&'static str>),
+    Conjunction(HashMap<&'static str, bool>, Vec<&'static str>),
+    Broadcast(Vec<&'static str>),
+}
+
+impl Module {
+    fn dests(&self) -> &[&'static str] {
+        match self {
+            Flip(_, dests) | Conjunction(_, dests) | Broadcast(dests) => dests,
+        }
+    }
+}
+
+/// The modules that send to `name`.
+fn senders(modules: &HashMap<&'static str, Module>, name: &str) -> Vec<&'static str> {
+    let mut senders = modules
+        .iter()
+        .filter(|(_, m)| m.dests().contains(&name))
+        .map(|(sender, _)| *sender)
+        .collect::<Vec<_>>();
+    senders.sort();
+    senders
+}
+
+fn parse_modules(input: &'static str) -> Result<HashMap<&'static str, Module>> {
+    let mut map: HashMap<&'static str, Module> = Default::default();
+    let mut connections: Vec<(&'static str, &'static str)> = Default::default();
+    for line in input.lines() {
+        let (_, (kind, name, dests)) = full(tuple((
+            opt(one_of("%&")),
+            alpha1,
+            preceded(
+                tag(" -> "),
+                separated_list1(
+                    tag(", "),
+                    alpha1,
+                ),
+            ),
+        )))(line)?;
+        let module = match kind {
+            Some('%') => Flip(false, dests.clone()),
+            Some('&') => Conjunction(HashMap::new(), dests.clone()),
+            _ if name == "broadcaster" => Broadcast(dests.clone()),
+            _ => return Err(error_at(name, "Expected a broadcaster or a `%` or `&` module")),
+        };
+        if map.insert(name, module).is_some() {
+            return Err(error_at(name, format!("There is already a module named {name}")));
+        }
+        dests.iter().for_each(|x| connections.push((name, x)));
+    }
+    debug!("Modules {map:?}");
+    for (send, receive) in &connections {
+        if !map.contains_key(receive) {
+            debug!("{send} sends to {receive}, which is not a module");
+        }
+        if let Some(Conjunction(memory, _ )) = map.get_mut(receive) {
+            memory.insert(send, false);
+        }
+    }
+
+    Ok(map)
+}
+
+const ON: Rgb = [96, 208, 112];
+const OFF: Rgb = [110, 110, 130];
+const HIGH: Rgb = [255, 120, 80];
+const LOW: Rgb = [90, 160, 255];
+
+/// Every module's state, with brackets round the one the last pulse went to. Flip-flops are lit
+/// while on, and conjunctions are coloured by the pulse they would send now.
+fn draw_circuit(modules: &HashMap<&str, Module>, pulse: (&str, &str, bool), queued: usize) -> String {
+    let (name, upstream, signal) = pulse;
+    let level = |high| if high { paint(HIGH, "high") } else { paint(LOW, "low") };
+    let mut out = format!("{upstream} -{}-> {name}, {queued} more queued\n\n", level(signal));
+    let mut names = modules.keys().copied().collect::<Vec<_>>();
+    names.sort();
+    let width = names.iter().map(|n| n.len()).max().unwrap_or(0) + 3;
+    for row in names.chunks(8) {
+        for module_name in row {
+            let (label, colour) = match &modules[module_name] {
+                Flip(on, _) => (format!("%{module_name}"), if *on { ON } else { OFF }),
+                Conjunction(inputs, _) => (format!("&{module_name}"), if inputs.values().all(|b| *b) { LOW } else { HIGH }),
+                Broadcast(_) => (module_name.to_string(), OFF),
+            };
+            let label = if *module_name == name { format!("[{label}]") } else { format!(" {label} ") };
+            out.push_str(&paint(colour, format!("{label:<width$}")));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn process_signal(modules: &mut HashMap<&str, Module>,
+                  queue: &mut VecDeque<(&'static str, (&'static str, bool))>,
+                  num_low: &mut u64,
+                  num_high: &mut u64,
+                  highs: &mut HashMap<&str, Vec<u64>>,
+                  count: &u64
+) {
+    if let Some((name, (upstream, signal))) = queue.pop_front() {
+        trace!("{upstream} -{}-> {name}", if signal {"high"} else {"low"});
+        if let Some(module) = modules.get_mut(name) {
+
+            match module {
+                Flip(state, dests) => {
+                    match signal {
+                        true => {},
+                        false => {
+                            *state = !*state;
+                            dests.iter().for_each(|d| {
+                                let output = *state;
+                                queue.push_back((d, (name, output)));
+                                if output {
+                                    *num_high += 1;
+                                } else {
+                                    *num_low += 1;
+                                }
+                            })
+                        }
+                    }
+                }
+                Conjunction(inputs, dests) => {
+                    inputs.insert(upstream, signal);
+                    let output = !inputs.iter().all(|(_, b)| *b);
+                    if output {
+                        if let Some(presses) = highs.get_mut(name) {
+                            presses.push(*count);
+                        }
+                    }
+                    dests.iter().for_each(|d| {
+                        queue.push_back((d, (name, output)));
+                        if output {
+                            *num_high += 1;
+                        } else {
+                            *num_low += 1;
+                        }
+                    })
+                }
+                Broadcast(dests) => {
+                    dests.iter().for_each(|d| {
+                        queue.push_back((d, (name, signal)));
+                        if signal {
+                            *num_high += 1;
+                        } else {
+                            *num_low += 1;
+                        }
+                    })
+                }
+            }
+
+
+        }
+        animate::frame("Day 20, pulses", || draw_circuit(modules, (name, upstream, signal), queue.len()));
+    } else {
+        panic!("Called on empty queue");
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Parsed = HashMap<&'static str, Module>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        parse_modules(input)
+    }
+
+    fn part_one(modules: &Self::Parsed) -> Result<u64> {
+        let mut mods = modules.clone();
+        let mut num_low = 0;
+        let mut num_high = 0;
+        let count = 0;
+
+        let mut queue: VecDeque<(&'static str, (&'static str, bool))> = Default::default();
+        let mut highs = HashMap::new();
+
+        for _ in 0..1000 {
+            queue.push_back(("broadcaster", ("button", false)));
+            num_low += 1;
+            while !queue.is_empty() {
+                process_signal(&mut mods, &mut queue,
+                                &mut num_low, &mut num_high, &mut highs, &count);
+            }
+        }
+
+        Ok(num_low * num_high)
+    }
+
+
+    /// `rx` is fed by a single conjunction, which only sends it a low pulse once all of its own
+    /// inputs have sent it high in the same press. Each of those inputs does so periodically.
+    fn part_two(modules: &Self::Parsed) -> Result<u64> {
+        let feeders = senders(modules, "rx");
+        let [feeder] = feeders[..] else {
+            bail!("Expected exactly one module sending to rx, found {feeders:?}");
+        };
+        let watched = senders(modules, feeder);
+
+        let mut mods = modules.clone();
+        let mut num_low = 0;
+        let mut num_high = 0;
+        let mut button_count = 0;
+
+        let mut queue: VecDeque<(&'static str, (&'static str, bool))> = Default::default();
+        let mut highs: HashMap<&str, Vec<u64>> = watched.iter().map(|name| (*name, Vec::new())).collect();
+
+        while highs.values().any(|presses| presses.len() < 3) {
+            if button_count == MAX_PRESSES {
+                bail!("No periods found for {watched:?} in {MAX_PRESSES} presses");
+            }
+            queue.push_back(("broadcaster", ("button", false)));
+            num_low += 1;
+            button_count += 1;
+            while !queue.is_empty() {
+                process_signal(&mut mods, &mut queue, &mut num_low, &mut num_high, &mut highs, &button_count);
+            }
+        }
+
+        let mut congruences = Vec::new();
+        let mut settled = 0;
+        for name in &watched {
+            let Some((first, length)) = period(&highs[name]) else {
+                bail!("{name} sends high irregularly, at {:?}", highs[name]);
+            };
+            debug!("{name} sends high every {length} presses from {first}");
+            congruences.push(Congruence::new(first, length));
+            settled = settled.max(first);
+        }
+        crt(congruences)?
+            .and_then(|c| c.first_from(settled))
+            .with_context(|| format!("{watched:?} never all send high on the same press"))
+    }
+}
+
+/// Circuits of two-letter modules, with or without a broadcaster, sending to each other and
+/// `rx`.
+#[cfg(test)]
+pub(crate) mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::days;
+    use crate::utils::rng::Rng;
+
+    pub fn modules(rng: &mut Rng) -> HashMap<&'static str, Module> {
+        let mut names = (0..rng.range(1..=6))
+            .map(|_| -> &'static str { (0..2).map(|_| (b'a' + rng.range(0..=25) as u8) as char).collect::<String>().leak() })
+            .unique()
+            .collect::<Vec<_>>();
+        if rng.chance(0.5) {
+            names.push("broadcaster");
+        }
+        let targets = names.iter().copied().chain(["rx"]).collect::<Vec<_>>();
+        let mut modules = names
+            .iter()
+            .map(|name| {
+                let dests = (0..rng.range(1..=3)).map(|_| *rng.pick(&targets)).collect();
+                let module = match (*name, rng.chance(0.5)) {
+                    ("broadcaster", _) => Broadcast(dests),
+                    (_, true) => Flip(false, dests),
+                    (_, false) => Conjunction(HashMap::new(), dests),
+                };
+                (*name, module)
+            })
+            .collect::<HashMap<_, _>>();
+        // Conjunctions start out remembering a low pulse from every module wired to them.
+        for name in &names {
+            for dest in modules[name].dests().to_vec() {
+                if let Some(Conjunction(memory, _)) = modules.get_mut(dest) {
+                    memory.insert(*name, false);
+                }
+            }
+        }
+        modules
+    }
+
+    pub fn print(modules: &HashMap<&'static str, Module>) -> String {
+        modules
+            .iter()
+            .map(|(name, module)| {
+                let kind = match module {
+                    Flip(..) => "%",
+                    Conjunction(..) => "&",
+                    Broadcast(_) => "",
+                };
+                format!("{kind}{name} -> {}\n", module.dests().join(", "))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn watched_module_with_several_destinations() {
+        // inv sends high on every press, once to each of its destinations.
+        let modules = Day20::parse("broadcaster -> inv\n&inv -> hub, zz\n&hub -> rx").unwrap();
+        assert_eq!(Day20::part_two(&modules).unwrap(), 1);
+    }
+
+    #[test]
+    fn duplicate_modules() {
+        let err = (days::get(20).unwrap().parse)("broadcaster -> ab\n%ab -> cd\n&ab -> rx").unwrap_err();
+        assert!(format!("{err:#}").contains("line 3, column 2"), "{err:#}");
+        assert!(format!("{err:#}").contains("There is already a module named ab"), "{err:#}");
+    }
+}
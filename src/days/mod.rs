@@ -0,0 +1,113 @@
+use std::any::Any;
+
+use anyhow::Result;
+
+use crate::image::Image;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+
+/// A type-erased [`Solution`], so days with different parsed and answer types can sit in one
+/// table and be picked by number at runtime.
+pub struct Day {
+    pub number: u8,
+    pub parse: fn(&'static str) -> Result<Box<dyn Any>>,
+    pub part_one: fn(&dyn Any) -> Result<String>,
+    pub part_two: fn(&dyn Any) -> Result<String>,
+    pub render: fn(&dyn Any) -> Result<Image>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day
+    where
+        S::Parsed: 'static,
+    {
+        Day {
+            number: S::DAY,
+            parse: parse::<S>,
+            part_one: part_one::<S>,
+            part_two: part_two::<S>,
+            render: render::<S>,
+        }
+    }
+}
+
+fn parse<S: Solution>(input: &'static str) -> Result<Box<dyn Any>>
+where
+    S::Parsed: 'static,
+{
+    match S::parse(input) {
+        Ok(parsed) => Ok(Box::new(parsed)),
+        Err(err) => Err(ParseError::new(S::DAY, input, &err).into()),
+    }
+}
+
+fn part_one<S: Solution>(parsed: &dyn Any) -> Result<String>
+where
+    S::Parsed: 'static,
+{
+    let parsed = parsed.downcast_ref().expect("parsed by a different day");
+    Ok(S::part_one(parsed)?.to_string())
+}
+
+fn part_two<S: Solution>(parsed: &dyn Any) -> Result<String>
+where
+    S::Parsed: 'static,
+{
+    let parsed = parsed.downcast_ref().expect("parsed by a different day");
+    Ok(S::part_two(parsed)?.to_string())
+}
+
+fn render<S: Solution>(parsed: &dyn Any) -> Result<Image>
+where
+    S::Parsed: 'static,
+{
+    S::render(parsed.downcast_ref().expect("parsed by a different day"))
+}
+
+pub const ALL: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+    Day::of::<day16::Day16>(),
+    Day::of::<day17::Day17>(),
+    Day::of::<day18::Day18>(),
+    Day::of::<day19::Day19>(),
+    Day::of::<day20::Day20>(),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    ALL.iter().find(|d| d.number == number)
+}
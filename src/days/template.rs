@@ -0,0 +1,27 @@
+//! https://adventofcode.com/2023/day/0
+
+use anyhow::Result;
+
+use crate::solution::Solution;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = 0;
+
+    type Parsed = Vec<&'static str>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_one(_lines: &Self::Parsed) -> Result<u64> {
+        Ok(0)
+    }
+
+    fn part_two(_lines: &Self::Parsed) -> Result<u64> {
+        Ok(0)
+    }
+}
@@ -0,0 +1,176 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+
+use crate::answers::Expected;
+use crate::input;
+
+/// One part's example, as found in the puzzle text.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    pub expected: String,
+}
+
+fn unescape(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Pulls the examples out of a saved puzzle page. Each part's description is its own
+/// `<article>`; the expected answer is the last emphasised code span in it, and the example
+/// is the last `<pre><code>` block before that answer. A part without a block of its own
+/// reuses the previous part's example.
+pub fn extract(html: &str) -> Result<Vec<Example>> {
+    let articles = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    let blocks = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answers = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+
+    let mut res: Vec<Example> = Vec::new();
+    for (index, article) in articles.captures_iter(html).enumerate() {
+        let part = index as u8 + 1;
+        let text = article.get(1).unwrap().as_str();
+        let Some(answer) = answers.captures_iter(text).last() else {
+            bail!("Part {part} has no emphasised answer");
+        };
+        let answer_at = answer.get(0).unwrap().start();
+        let block = blocks
+            .captures_iter(text)
+            .filter(|b| b.get(0).unwrap().end() <= answer_at)
+            .last();
+        let input = match (block, res.last()) {
+            (Some(block), _) => unescape(block.get(1).unwrap().as_str()),
+            (None, Some(previous)) => previous.input.clone(),
+            (None, None) => bail!("Part {part} has no example block"),
+        };
+        res.push(Example {
+            part,
+            input,
+            expected: unescape(answer.get(1).unwrap().as_str()),
+        });
+    }
+    if res.is_empty() {
+        bail!("No puzzle description found in the page");
+    }
+    Ok(res)
+}
+
+/// The metadata file listing each example's input file and expected answer, in the same
+/// format as the regression answers file.
+pub fn metadata_path(day: u8, year: u16) -> PathBuf {
+    input::inputs_dir().join(format!("examples-{day:02}-{year}.tsv"))
+}
+
+fn example_file(day: u8, year: u16, part: u8) -> PathBuf {
+    match part {
+        1 => input::example_path(day, year),
+        _ => input::inputs_dir().join(format!("test-{day:02}-{year}-{part}.txt")),
+    }
+}
+
+fn write_new(path: &PathBuf, contents: &str, force: bool) -> Result<()> {
+    let occupied = fs::metadata(path).map(|m| m.len() > 0).unwrap_or(false);
+    if occupied && !force {
+        bail!("{} already has content, not overwriting it", path.display());
+    }
+    fs::write(path, contents).with_context(|| format!("Writing {}", path.display()))
+}
+
+/// Writes the example inputs and their metadata into the inputs directory. Parts that share
+/// an example share a file. Returns the files written.
+pub fn save(day: u8, year: u16, examples: &[Example], force: bool) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(input::inputs_dir())?;
+    let mut written = Vec::new();
+    let mut metadata = String::new();
+    let mut previous: Option<(&str, PathBuf)> = None;
+    for example in examples {
+        let path = match &previous {
+            Some((input, path)) if *input == example.input => path.clone(),
+            _ => {
+                let path = example_file(day, year, example.part);
+                write_new(&path, &example.input, force)?;
+                written.push(path.clone());
+                path
+            }
+        };
+        let expected = Expected {
+            day,
+            part: example.part,
+            input: path.file_name().unwrap().to_string_lossy().into_owned(),
+            expected: example.expected.clone(),
+        };
+        metadata.push_str(&format!("{expected}\n"));
+        previous = Some((&example.input, path));
+    }
+    let path = metadata_path(day, year);
+    write_new(&path, &metadata, force)?;
+    written.push(path);
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trimmed from a saved puzzle page: the second part has an example of its own, and the
+    /// answers are the last emphasised code in each part, after other emphasised code.
+    const OWN_EXAMPLES: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>The values of these lines are <code><em>12</em></code> and <code><em>38</em></code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54331</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>For example:</p>
+<pre><code>two1nine
+<em>x</em>&lt;4&amp;five
+</code></pre>
+<p>Adding these together produces <code><em>74</em></code>.</p>
+</article>
+</main>"#;
+
+    /// The second part asks something new of the first part's example.
+    const SHARED_EXAMPLE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 9: Mirage Maintenance ---</h2>
+<pre><code>0 3 6
+1 3 6
+</code></pre>
+<p>The sum of these extrapolated values is <code><em>114</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Adding the new values together produces <code><em>2</em></code>.</p>
+</article>
+</main>"#;
+
+    fn example(part: u8, input: &str, expected: &str) -> Example {
+        Example {
+            part,
+            input: input.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    #[test]
+    fn extracts_examples() {
+        assert_eq!(
+            extract(OWN_EXAMPLES).unwrap(),
+            [example(1, "1abc2\npqr3stu8vwx\n", "142"), example(2, "two1nine\nx<4&five\n", "74")]
+        );
+        assert_eq!(
+            extract(SHARED_EXAMPLE).unwrap(),
+            [example(1, "0 3 6\n1 3 6\n", "114"), example(2, "0 3 6\n1 3 6\n", "2")]
+        );
+        assert!(extract("<main>No puzzle here</main>").is_err());
+    }
+}
@@ -0,0 +1,447 @@
+//! `aoc generate`: random puzzle inputs of any size, for stress tests and for comparing
+//! implementations on inputs far bigger than the real ones. The same day, size and seed always
+//! give the same input.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+
+use crate::utils::rng::Rng;
+use crate::utils::Dir;
+
+/// The days with a generator.
+pub const DAYS: &[u8] = &[5, 10, 19, 20];
+
+/// What `size` means for `day`, and the size that is about as big as the real input.
+pub fn describe(day: u8) -> Option<(&'static str, usize)> {
+    match day {
+        5 => Some(("mappings in each map", 40)),
+        10 => Some(("width and height of the maze", 140)),
+        19 => Some(("workflows", 550)),
+        20 => Some(("binary counters feeding rx", 4)),
+        _ => None,
+    }
+}
+
+/// A random input for `day`.
+pub fn generate(day: u8, size: usize, seed: u64) -> Result<String> {
+    let rng = &mut Rng::new(seed);
+    Ok(match day {
+        5 => almanac(rng, size.max(1)),
+        10 => maze(rng, size.max(3)),
+        19 => workflows(rng, size.max(1)),
+        20 => circuit(rng, size.max(1)).0,
+        _ => bail!("There is no generator for day {day}"),
+    })
+}
+
+/// Distinct lowercase names, none of them `reserved`, at least `len` letters long and longer if
+/// that many short names would be hard to find.
+fn names(rng: &mut Rng, count: usize, mut len: usize, reserved: &[&str]) -> Vec<String> {
+    while 26f64.powi(len as i32) < 2.0 * (count + reserved.len()) as f64 {
+        len += 1;
+    }
+    let mut seen = reserved
+        .iter()
+        .map(|r| r.to_string())
+        .collect::<HashSet<_>>();
+    let mut names = Vec::new();
+    while names.len() < count {
+        let name = (0..len)
+            .map(|_| (b'a' + rng.range(0..=25) as u8) as char)
+            .collect::<String>();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+const ALMANAC_MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+const ALMANAC_LIMIT: i64 = 1 << 32;
+
+/// Day 5: seed ranges and seven maps, each with `size` mappings whose sources don't overlap.
+fn almanac(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::from("seeds:");
+    for _ in 0..10 {
+        let start = rng.range(0..=ALMANAC_LIMIT / 2);
+        write!(text, " {start} {}", rng.range(1..=ALMANAC_LIMIT / 20)).unwrap();
+    }
+    text.push('\n');
+    for name in ALMANAC_MAPS {
+        write!(text, "\n{name} map:\n").unwrap();
+        // Cut points split the values into segments, most of which get mapped somewhere.
+        let mut cuts = (0..size * 2)
+            .map(|_| rng.range(0..=ALMANAC_LIMIT))
+            .collect::<Vec<_>>();
+        cuts.sort();
+        cuts.dedup();
+        let mut mappings = cuts
+            .windows(2)
+            .step_by(2)
+            .map(|w| {
+                (
+                    rng.range(0..=ALMANAC_LIMIT - (w[1] - w[0])),
+                    w[0],
+                    w[1] - w[0],
+                )
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut mappings);
+        for (dest, source, len) in mappings {
+            writeln!(text, "{dest} {source} {len}").unwrap();
+        }
+    }
+    text
+}
+
+/// Day 10: a `size` by `size` field of pipes holding one loop, with junk pipes around it.
+///
+/// The loop runs round a random tree of cells on a grid at a third of the resolution. Each cell
+/// is a 3x3 ring of pipes round one tile, and each tree edge splices the rings of the cells it
+/// joins into one, so the tree's rings end up as a single loop with the cells' middles and the
+/// gaps between them inside it.
+fn maze(rng: &mut Rng, size: usize) -> String {
+    let cells = size / 3;
+    let mut in_tree = vec![vec![false; cells]; cells];
+    let (x, y) = (rng.index(cells), rng.index(cells));
+    in_tree[y][x] = true;
+    let mut frontier = Dir::ALL.map(|d| ((x, y), d)).to_vec();
+    let mut edges = Vec::new();
+    let target = (cells * cells / 2).max(1);
+    let mut grown = 1;
+    while grown < target && !frontier.is_empty() {
+        let ((x, y), dir) = frontier.swap_remove(rng.index(frontier.len()));
+        let step = dir.to_ivec2();
+        let (nx, ny) = (x as i32 + step.x, y as i32 + step.y);
+        if nx < 0
+            || ny < 0
+            || nx >= cells as i32
+            || ny >= cells as i32
+            || in_tree[ny as usize][nx as usize]
+        {
+            continue;
+        }
+        let next = (nx as usize, ny as usize);
+        in_tree[next.1][next.0] = true;
+        edges.push(((x, y), dir));
+        frontier.extend(Dir::ALL.map(|d| (next, d)));
+        grown += 1;
+    }
+
+    // Which of its neighbours each tile's pipe connects to.
+    let mut links = vec![vec![Vec::<Dir>::new(); size]; size];
+    for (y, row) in in_tree.iter().enumerate() {
+        for (x, _) in row.iter().enumerate().filter(|(_, inside)| **inside) {
+            let (fx, fy) = (3 * x, 3 * y);
+            links[fy][fx] = vec![Dir::East, Dir::South];
+            links[fy][fx + 1] = vec![Dir::East, Dir::West];
+            links[fy][fx + 2] = vec![Dir::West, Dir::South];
+            links[fy + 1][fx] = vec![Dir::North, Dir::South];
+            links[fy + 1][fx + 2] = vec![Dir::North, Dir::South];
+            links[fy + 2][fx] = vec![Dir::North, Dir::East];
+            links[fy + 2][fx + 1] = vec![Dir::East, Dir::West];
+            links[fy + 2][fx + 2] = vec![Dir::North, Dir::West];
+        }
+    }
+    let mut swap = |(x, y): (usize, usize), from: Dir, to: Dir| {
+        let tile = &mut links[y][x];
+        tile.retain(|d| *d != from);
+        tile.push(to);
+    };
+    let mut gaps = Vec::new();
+    for ((x, y), dir) in edges {
+        // Normalise so the edge runs east or south from (x, y).
+        let ((x, y), dir) = match dir {
+            Dir::West => ((x - 1, y), Dir::East),
+            Dir::North => ((x, y - 1), Dir::South),
+            d => ((x, y), d),
+        };
+        let (fx, fy) = (3 * x, 3 * y);
+        if dir == Dir::East {
+            swap((fx + 2, fy), Dir::South, Dir::East);
+            swap((fx + 2, fy + 2), Dir::North, Dir::East);
+            swap((fx + 3, fy), Dir::South, Dir::West);
+            swap((fx + 3, fy + 2), Dir::North, Dir::West);
+            gaps.extend([(fx + 2, fy + 1), (fx + 3, fy + 1)]);
+        } else {
+            swap((fx, fy + 2), Dir::East, Dir::South);
+            swap((fx + 2, fy + 2), Dir::West, Dir::South);
+            swap((fx, fy + 3), Dir::East, Dir::North);
+            swap((fx + 2, fy + 3), Dir::West, Dir::North);
+            gaps.extend([(fx + 1, fy + 2), (fx + 1, fy + 3)]);
+        }
+    }
+    // The sides between joined cells open up, leaving them inside the loop.
+    for (x, y) in gaps {
+        links[y][x].clear();
+    }
+
+    let pipe = |dirs: &[Dir]| {
+        let has = |d| dirs.contains(&d);
+        match (
+            has(Dir::North),
+            has(Dir::East),
+            has(Dir::South),
+            has(Dir::West),
+        ) {
+            (true, false, true, false) => '|',
+            (false, true, false, true) => '-',
+            (true, true, false, false) => 'L',
+            (true, false, false, true) => 'J',
+            (false, false, true, true) => '7',
+            (false, true, true, false) => 'F',
+            _ => unreachable!("every loop tile has two links, got {dirs:?}"),
+        }
+    };
+    let mut grid = links
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|dirs| {
+                    if dirs.is_empty() {
+                        *rng.pick(&['.', '.', '|', '-', 'L', 'J', '7', 'F'])
+                    } else {
+                        pipe(dirs)
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // The start goes on a loop tile with ground on its neighbours off the loop, so its pipe can
+    // only be read one way. Loop tiles next to it only point at it if they're linked to it.
+    let loop_tiles = (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .filter(|&(x, y)| !links[y][x].is_empty())
+        .collect::<Vec<_>>();
+    let (sx, sy) = *rng.pick(&loop_tiles);
+    for dir in Dir::ALL {
+        let step = dir.to_ivec2();
+        let (nx, ny) = (sx as i32 + step.x, sy as i32 + step.y);
+        if nx >= 0
+            && ny >= 0
+            && (nx as usize) < size
+            && (ny as usize) < size
+            && links[ny as usize][nx as usize].is_empty()
+        {
+            grid[ny as usize][nx as usize] = '.';
+        }
+    }
+    grid[sy][sx] = 'S';
+
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// Day 19: `size` workflows that only ever send parts on to later workflows, so there are no
+/// cycles, and twice as many parts.
+fn workflows(rng: &mut Rng, size: usize) -> String {
+    let mut names = names(rng, size - 1, 3, &["in"]);
+    names.insert(0, "in".to_string());
+    let mut text = String::new();
+    for (i, name) in names.iter().enumerate() {
+        let later = &names[i + 1..];
+        let dest = |rng: &mut Rng| {
+            if later.is_empty() || rng.chance(0.3) {
+                rng.pick(&["A", "R"]).to_string()
+            } else {
+                rng.pick(later).clone()
+            }
+        };
+        let mut rules = Vec::new();
+        for _ in 0..rng.range(1..=4) {
+            let property = rng.pick(&['x', 'm', 'a', 's']);
+            let op = rng.pick(&['<', '>']);
+            rules.push(format!(
+                "{property}{op}{}:{}",
+                rng.range(1..=4000),
+                dest(rng)
+            ));
+        }
+        rules.push(dest(rng));
+        writeln!(text, "{name}{{{}}}", rules.join(",")).unwrap();
+    }
+    text.push('\n');
+    for _ in 0..size * 2 {
+        let mut rating = || rng.range(1..=4000);
+        writeln!(
+            text,
+            "{{x={},m={},a={},s={}}}",
+            rating(),
+            rating(),
+            rating(),
+            rating()
+        )
+        .unwrap();
+    }
+    text
+}
+
+const COUNTER_BITS: usize = 12;
+
+/// Day 20: the broadcaster drives `size` 12-bit binary counters. Each counter's conjunction
+/// fires when it reaches its own random period, resets it, and pulses an inverter that feeds
+/// the conjunction in front of `rx`. Returns the circuit and the counters' periods.
+fn circuit(rng: &mut Rng, size: usize) -> (String, Vec<u64>) {
+    let mut pool = names(rng, size * (COUNTER_BITS + 2) + 1, 2, &["rx"]).into_iter();
+    let mut next = || pool.next().expect("generated enough names");
+    let last = next();
+    let mut lines = Vec::new();
+    let mut starts = Vec::new();
+    let mut periods = Vec::new();
+    for _ in 0..size {
+        let bits = (0..COUNTER_BITS).map(|_| next()).collect::<Vec<_>>();
+        let (hub, inverter) = (next(), next());
+        // Odd with the top bit set, so the first flip-flop and the last both feed the hub.
+        let period = rng.range(1 << (COUNTER_BITS - 1)..=(1 << COUNTER_BITS) - 1) | 1;
+        let mut hub_dests = Vec::new();
+        for (i, bit) in bits.iter().enumerate() {
+            let mut dests = bits.get(i + 1).into_iter().cloned().collect::<Vec<_>>();
+            if period >> i & 1 == 1 {
+                dests.push(hub.clone());
+            }
+            if period >> i & 1 == 0 || i == 0 {
+                hub_dests.push(bit.clone());
+            }
+            lines.push(format!("%{bit} -> {}", dests.join(", ")));
+        }
+        hub_dests.push(inverter.clone());
+        lines.push(format!("&{hub} -> {}", hub_dests.join(", ")));
+        lines.push(format!("&{inverter} -> {last}"));
+        starts.push(bits[0].clone());
+        periods.push(period as u64);
+    }
+    lines.push(format!("&{last} -> rx"));
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut lines);
+    (lines.iter().map(|line| format!("{line}\n")).collect(), periods)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::days;
+    use crate::utils::number::lcm;
+
+    fn solve(day: u8, text: String) -> (String, String) {
+        let day = days::get(day).unwrap();
+        let parsed = (day.parse)(text.leak()).unwrap();
+        (
+            (day.part_one)(parsed.as_ref()).unwrap(),
+            (day.part_two)(parsed.as_ref()).unwrap(),
+        )
+    }
+
+    /// Day 5 part one the slow way, taking each seed through each map in turn.
+    fn lowest_location(text: &str) -> i64 {
+        let mut sections = text.split("\n\n");
+        let seeds = sections.next().unwrap().strip_prefix("seeds: ").unwrap();
+        let mut values = seeds.split_whitespace().map(|n| n.parse::<i64>().unwrap()).collect::<Vec<_>>();
+        for section in sections {
+            let mappings = section
+                .lines()
+                .skip(1)
+                .map(|line| line.split(' ').map(|n| n.parse::<i64>().unwrap()).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            for value in &mut values {
+                if let Some(m) = mappings.iter().find(|m| (m[1]..m[1] + m[2]).contains(value)) {
+                    *value += m[0] - m[1];
+                }
+            }
+        }
+        values.into_iter().min().unwrap()
+    }
+
+    /// Day 19 part one the slow way, reading each rule's text as the part reaches it.
+    fn accepted_ratings(text: &str) -> u64 {
+        let (workflows, parts) = text.split_once("\n\n").unwrap();
+        let workflows = workflows
+            .lines()
+            .map(|line| {
+                let (name, rules) = line.strip_suffix('}').unwrap().split_once('{').unwrap();
+                (name, rules.split(',').collect::<Vec<_>>())
+            })
+            .collect::<HashMap<_, _>>();
+        parts
+            .lines()
+            .map(|line| {
+                let ratings = line
+                    .trim_matches(['{', '}'])
+                    .split(',')
+                    .map(|r| r[2..].parse::<u64>().unwrap())
+                    .collect::<Vec<_>>();
+                let mut at = "in";
+                while !matches!(at, "A" | "R") {
+                    at = workflows[at]
+                        .iter()
+                        .find_map(|rule| {
+                            let Some((condition, dest)) = rule.split_once(':') else {
+                                return Some(*rule);
+                            };
+                            let value = ratings["xmas".find(&condition[..1]).unwrap()];
+                            let limit = condition[2..].parse::<u64>().unwrap();
+                            let holds = if &condition[1..2] == "<" { value < limit } else { value > limit };
+                            holds.then_some(dest)
+                        })
+                        .unwrap();
+                }
+                if at == "A" {
+                    ratings.iter().sum()
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn seeded() {
+        for &day in DAYS {
+            assert_eq!(generate(day, 6, 1).unwrap(), generate(day, 6, 1).unwrap());
+            assert_ne!(generate(day, 6, 1).unwrap(), generate(day, 6, 2).unwrap());
+        }
+        assert!(generate(1, 6, 1).is_err());
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..5 {
+            let text = generate(5, 8, seed).unwrap();
+            assert_eq!(solve(5, text.clone()).0, lowest_location(&text).to_string());
+            let text = generate(19, 20, seed).unwrap();
+            assert_eq!(solve(19, text.clone()).0, accepted_ratings(&text).to_string());
+
+            // Half of the cells are in the tree, joined by one fewer edges. Each cell's ring has 8
+            // tiles round 1 inside, and each join moves 2 loop tiles inside. The loop is a single
+            // curve, so cells the tree wraps round without including stay outside.
+            for (size, cells) in [(12, 8), (31, 50)] {
+                let (far, inside) = solve(10, generate(10, size, seed).unwrap());
+                assert_eq!(far, ((8 * cells - 2 * (cells - 1)) / 2).to_string(), "size {size}");
+                assert_eq!(inside, (cells + 2 * (cells - 1)).to_string(), "size {size}");
+            }
+        }
+    }
+
+    #[test]
+    fn circuits_repeat_at_their_periods() {
+        for seed in 0..5 {
+            let (text, periods) = circuit(&mut Rng::new(seed), 3);
+            let (_, presses) = solve(20, text);
+            assert_eq!(presses, lcm(periods).unwrap().to_string());
+        }
+    }
+}
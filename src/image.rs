@@ -0,0 +1,213 @@
+//! Pictures of puzzle states, for `aoc render`. Days draw into an [`Image`] with their own
+//! palette, and it is saved as PNG or PPM depending on the file's extension.
+//!
+//! Both formats are written by hand. The PNG is uncompressed, which costs some size but keeps
+//! the encoder short and free of dependencies.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use glam::IVec2;
+
+use crate::utils::Grid;
+
+pub type Rgb = [u8; 3];
+
+/// `colour` scaled towards black by `fraction`, which should be between 0 and 1.
+pub fn shade(colour: Rgb, fraction: f64) -> Rgb {
+    colour.map(|c| (c as f64 * fraction.clamp(0.0, 1.0)).round() as u8)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// One pixel per cell, coloured by `palette`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut palette: impl FnMut(IVec2, &T) -> Rgb) -> Self {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(|(point, cell)| palette(point, cell)).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, point: IVec2) -> Option<Rgb> {
+        self.offset(point).map(|i| self.pixels[i])
+    }
+
+    /// Colours the pixel at `point`, ignoring points outside the image.
+    pub fn set(&mut self, point: IVec2, colour: Rgb) {
+        if let Some(i) = self.offset(point) {
+            self.pixels[i] = colour;
+        }
+    }
+
+    /// Colours the `size` rectangle with its top-left corner at `corner`.
+    pub fn fill(&mut self, corner: IVec2, size: IVec2, colour: Rgb) {
+        for y in 0..size.y {
+            for x in 0..size.x {
+                self.set(corner + IVec2::new(x, y), colour);
+            }
+        }
+    }
+
+    fn offset(&self, point: IVec2) -> Option<usize> {
+        let inside = point.x >= 0 && point.y >= 0 && (point.x as usize) < self.width && (point.y as usize) < self.height;
+        inside.then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    /// Each pixel blown up to a `factor` by `factor` square, since a pixel per tile is hard to
+    /// see.
+    pub fn scaled(&self, factor: usize) -> Image {
+        let mut pixels = Vec::with_capacity(self.pixels.len() * factor * factor);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            for _ in 0..factor {
+                pixels.extend(row.iter().flat_map(|p| std::iter::repeat_n(*p, factor)));
+            }
+        }
+        Image {
+            width: self.width * factor,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// A binary PPM (P6): a text header and then the raw pixels.
+    pub fn ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().flatten());
+        out
+    }
+
+    /// An 8-bit RGB PNG with no filtering and the image data in stored deflate blocks.
+    pub fn png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(self.pixels.len() * 3 + self.height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // Bit depth 8, colour type 2 (RGB), then the default compression, filter and interlace.
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        chunk(&mut out, b"IHDR", &header);
+        chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Writes the image as PNG or PPM, going by the extension of `path`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.png(),
+            Some("ppm") => self.ppm(),
+            _ => bail!("Can't tell the image format of {}, expected a .png or .ppm file", path.display()),
+        };
+        fs::write(path, bytes).with_context(|| format!("Writing {}", path.display()))
+    }
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// `data` wrapped as a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+    let mut out = vec![0x78, 0x01];
+    let blocks = data.chunks(MAX_BLOCK).collect::<Vec<_>>();
+    if blocks.is_empty() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    for (i, block) in blocks.iter().enumerate() {
+        let last = i + 1 == blocks.len();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(*block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn encodings() {
+        let mut image = Image::new(2, 1, [0, 0, 0]);
+        image.set(IVec2::new(1, 0), [255, 128, 1]);
+        image.set(IVec2::new(2, 0), [9, 9, 9]);
+        assert_eq!(image.ppm(), b"P6\n2 1\n255\n\x00\x00\x00\xff\x80\x01");
+
+        let scaled = image.scaled(2);
+        assert_eq!((scaled.width(), scaled.height()), (4, 2));
+        assert_eq!(scaled.get(IVec2::new(3, 1)), Some([255, 128, 1]));
+        assert_eq!(scaled.get(IVec2::new(1, 1)), Some([0, 0, 0]));
+
+        let png = image.png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR\x00\x00\x00\x02\x00\x00\x00\x01"));
+        assert!(png.ends_with(b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"));
+        // One filter byte and six bytes of pixels in a single final stored block.
+        let idat = png.windows(4).position(|w| w == b"IDAT").unwrap();
+        assert_eq!(&png[idat + 4..idat + 13], [0x78, 0x01, 1, 7, 0, 0xf8, 0xff, 0, 0]);
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+pub const YEAR: u16 = 2023;
+
+/// Where a day's puzzle input should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The personal puzzle input, `inputs/input-NN-YYYY.txt`.
+    Real,
+    /// The example from the puzzle text, `inputs/test-NN-YYYY.txt`.
+    Example,
+    /// Any other file.
+    Path(PathBuf),
+}
+
+impl Source {
+    pub fn path(&self, day: u8) -> PathBuf {
+        match self {
+            Source::Real => real_path(day, YEAR),
+            Source::Example => example_path(day, YEAR),
+            Source::Path(path) => path.clone(),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Real => write!(f, "real input"),
+            Source::Example => write!(f, "example input"),
+            Source::Path(path) => write!(f, "input {}", path.display()),
+        }
+    }
+}
+
+pub fn inputs_dir() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"))
+}
+
+pub fn real_name(day: u8, year: u16) -> String {
+    format!("input-{day:02}-{year}.txt")
+}
+
+pub fn example_name(day: u8, year: u16) -> String {
+    format!("test-{day:02}-{year}.txt")
+}
+
+pub fn real_path(day: u8, year: u16) -> PathBuf {
+    inputs_dir().join(real_name(day, year))
+}
+
+pub fn example_path(day: u8, year: u16) -> PathBuf {
+    inputs_dir().join(example_name(day, year))
+}
+
+/// Loads a day's input. The text is leaked so solutions can keep borrowing from it for the
+/// rest of the run, the same way they did when it was embedded with `include_str!`.
+pub fn load(day: u8, source: &Source) -> Result<&'static str> {
+    let path = source.path(day);
+    if !path.is_file() {
+        match source {
+            Source::Real => bail!(
+                "Missing {source} for day {day}: {} does not exist (fetch it with `aoc fetch {day}`)",
+                path.display()
+            ),
+            _ => bail!("Missing {source} for day {day}: {} does not exist", path.display()),
+        }
+    }
+    let text = fs::read_to_string(&path)
+        .with_context(|| format!("Reading {source} for day {day} from {}", path.display()))?;
+    Ok(Box::leak(text.into_boxed_str()))
+}
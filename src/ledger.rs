@@ -0,0 +1,236 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+
+use crate::input;
+
+/// What the puzzle server said about a submitted answer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    /// Rate limited; the answer was not checked.
+    Wait,
+}
+
+impl Verdict {
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "wait" => Ok(Verdict::Wait),
+            _ => bail!("Bad verdict {s}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub value: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted so far, one tab separated line per attempt:
+/// `year day part value verdict`.
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    pub fn default_path() -> PathBuf {
+        input::inputs_dir().join("ledger.tsv")
+    }
+
+    pub fn open(path: PathBuf) -> Result<Ledger> {
+        let attempts = if path.exists() {
+            fs::read_to_string(&path)
+                .with_context(|| format!("Reading {}", path.display()))?
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(i, line)| {
+                    parse_attempt(line)
+                        .with_context(|| format!("{}:{}: bad ledger entry", path.display(), i + 1))
+                })
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            Vec::new()
+        };
+        Ok(Ledger { path, attempts })
+    }
+
+    pub fn attempts(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// Fails if the ledger already rules the answer out: the part is solved, this exact value
+    /// was wrong before, or a numeric value falls outside a recorded too high / too low bound.
+    pub fn check(&self, year: u16, day: u8, part: u8, value: &str) -> Result<()> {
+        let mut lowest_too_high: Option<(i128, &str)> = None;
+        let mut highest_too_low: Option<(i128, &str)> = None;
+        for attempt in self.attempts(year, day, part) {
+            if attempt.verdict == Verdict::Correct {
+                bail!("Day {day} part {part} is already solved: {}", attempt.value);
+            }
+            if attempt.verdict.is_wrong() && attempt.value == value {
+                bail!("{value} was already submitted for day {day} part {part}: {}", attempt.verdict);
+            }
+            let Ok(num) = attempt.value.parse::<i128>() else {
+                continue;
+            };
+            match attempt.verdict {
+                Verdict::TooHigh if lowest_too_high.is_none_or(|(n, _)| num < n) => {
+                    lowest_too_high = Some((num, &attempt.value));
+                }
+                Verdict::TooLow if highest_too_low.is_none_or(|(n, _)| num > n) => {
+                    highest_too_low = Some((num, &attempt.value));
+                }
+                _ => {}
+            }
+        }
+        if let Ok(num) = value.parse::<i128>() {
+            if let Some((high, high_text)) = lowest_too_high {
+                if num >= high {
+                    bail!("{value} is not below {high_text}, which was already too high");
+                }
+            }
+            if let Some((low, low_text)) = highest_too_low {
+                if num <= low {
+                    bail!("{value} is not above {low_text}, which was already too low");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Opening {}", self.path.display()))?;
+        writeln!(file, "{}", format_attempt(&attempt))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn format_attempt(attempt: &Attempt) -> String {
+    format!(
+        "{}\t{}\t{}\t{}\t{}",
+        attempt.year, attempt.day, attempt.part, attempt.value, attempt.verdict
+    )
+}
+
+fn parse_attempt(line: &str) -> Result<Attempt> {
+    let fields = line.split('\t').collect::<Vec<_>>();
+    let [year, day, part, value, verdict] = fields[..] else {
+        bail!("expected 5 tab separated fields, found {}", fields.len());
+    };
+    Ok(Attempt {
+        year: year.parse()?,
+        day: day.parse()?,
+        part: part.parse()?,
+        value: value.to_string(),
+        verdict: verdict.parse()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(part: u8, value: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            year: 2023,
+            day: 5,
+            part,
+            value: value.to_string(),
+            verdict,
+        }
+    }
+
+    fn with_attempts(attempts: Vec<Attempt>) -> Ledger {
+        Ledger {
+            path: PathBuf::new(),
+            attempts,
+        }
+    }
+
+    #[test]
+    fn refuses_what_is_ruled_out() {
+        let ledger = with_attempts(vec![
+            attempt(1, "abc", Verdict::Wrong),
+            attempt(1, "100", Verdict::TooHigh),
+            attempt(1, "120", Verdict::TooHigh),
+            attempt(1, "40", Verdict::TooLow),
+            attempt(1, "70", Verdict::Wait),
+            attempt(2, "5", Verdict::Correct),
+        ]);
+        let check = |part, value| ledger.check(2023, 5, part, value).map_err(|err| err.to_string());
+
+        assert_eq!(check(1, "abc"), Err("abc was already submitted for day 5 part 1: wrong".into()));
+        assert_eq!(check(1, "100"), Err("100 was already submitted for day 5 part 1: too-high".into()));
+        assert_eq!(check(1, "110"), Err("110 is not below 100, which was already too high".into()));
+        assert_eq!(check(1, "12"), Err("12 is not above 40, which was already too low".into()));
+        assert_eq!(check(1, "40"), Err("40 was already submitted for day 5 part 1: too-low".into()));
+        assert_eq!(check(1, "70"), Ok(()));
+        assert_eq!(check(1, "99"), Ok(()));
+        assert_eq!(check(1, "xyz"), Ok(()));
+        assert_eq!(check(2, "6"), Err("Day 5 part 2 is already solved: 5".into()));
+
+        // Bounds from part one say nothing about part two, or about other days.
+        let ledger = with_attempts(vec![attempt(1, "100", Verdict::TooHigh), attempt(1, "40", Verdict::TooLow)]);
+        assert!(ledger.check(2023, 5, 2, "500").is_ok());
+        assert!(ledger.check(2023, 6, 1, "500").is_ok());
+    }
+
+    #[test]
+    fn attempts_round_trip() {
+        for verdict in [Verdict::Correct, Verdict::TooHigh, Verdict::TooLow, Verdict::Wrong, Verdict::Wait] {
+            let attempt = attempt(2, "-1234 5", verdict);
+            assert_eq!(parse_attempt(&format_attempt(&attempt)).unwrap(), attempt);
+        }
+        assert!(parse_attempt("2023\t5\t1\t42").is_err());
+        assert!(parse_attempt("2023\t5\t1\t42\tmaybe").is_err());
+        assert!(parse_attempt("2023\tfive\t1\t42\twrong").is_err());
+    }
+}
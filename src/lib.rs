@@ -0,0 +1,16 @@
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod examples;
+pub mod generate;
+pub mod image;
+pub mod input;
+pub mod ledger;
+pub mod parallel;
+pub mod parse;
+pub mod scaffold;
+pub mod solution;
+pub mod trace;
+pub mod utils;
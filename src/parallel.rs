@@ -0,0 +1,135 @@
+//! Spreading independent work over threads, for whole days and for the brute-force loops inside
+//! them.
+//!
+//! There is one [`Budget`] of threads for the whole process, set with [`set_threads`]. [`map`]
+//! only spawns workers while the budget has room and does the rest on the calling thread, so days
+//! run side by side don't also multiply the threads each of them asks for. With the default budget
+//! of one thread, everything runs in order on the caller.
+
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+static BUDGET: Budget = Budget::new();
+
+/// Allows up to `threads` threads at once across the process, or one per core when `threads` is
+/// 0.
+pub fn set_threads(threads: usize) {
+    BUDGET.set_threads(threads);
+}
+
+pub fn threads() -> usize {
+    BUDGET.threads()
+}
+
+/// `f` applied to each of `items`, sharing the process's budget. See [`Budget::map`].
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    BUDGET.map(items, f)
+}
+
+/// A limit on the threads working at once, shared by every [`Budget::map`] on it, nested or not.
+#[derive(Debug)]
+pub struct Budget {
+    threads: AtomicUsize,
+    /// Threads currently doing work, counting the main one.
+    busy: AtomicUsize,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget::new()
+    }
+}
+
+impl Budget {
+    /// A budget of one thread, the caller's.
+    pub const fn new() -> Budget {
+        Budget {
+            threads: AtomicUsize::new(1),
+            busy: AtomicUsize::new(1),
+        }
+    }
+
+    /// Allows up to `threads` threads at once, or one per core when `threads` is 0.
+    pub fn set_threads(&self, threads: usize) {
+        let threads = match threads {
+            0 => thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        };
+        self.threads.store(threads, Ordering::Relaxed);
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads.load(Ordering::Relaxed)
+    }
+
+    fn claim(&self, wanted: usize) -> Claim<'_> {
+        let mut taken = 0;
+        let _ = self.busy.fetch_update(Ordering::AcqRel, Ordering::Acquire, |busy| {
+            taken = self.threads().saturating_sub(busy).min(wanted);
+            Some(busy + taken)
+        });
+        Claim(self, taken)
+    }
+
+    /// `f` applied to each of `items`, on as many threads as the budget allows. Results come
+    /// back in the order of `items` however the work was split up.
+    pub fn map<T: Sync, R: Send>(&self, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+        let claim = self.claim(items.len().saturating_sub(1));
+        if claim.1 == 0 {
+            return items.iter().map(f).collect();
+        }
+
+        let next = AtomicUsize::new(0);
+        let work = || {
+            let mut done = Vec::new();
+            loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    return done;
+                };
+                done.push((i, f(item)));
+            }
+        };
+        let mut done = thread::scope(|scope| {
+            let workers = (0..claim.1).map(|_| scope.spawn(work)).collect::<Vec<_>>();
+            let mut done = work();
+            for worker in workers {
+                done.extend(worker.join().unwrap_or_else(|err| panic::resume_unwind(err)));
+            }
+            done
+        });
+        drop(claim);
+
+        done.sort_unstable_by_key(|(i, _)| *i);
+        done.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+/// Workers taken from a budget, handed back when dropped.
+struct Claim<'a>(&'a Budget, usize);
+
+impl Drop for Claim<'_> {
+    fn drop(&mut self) {
+        self.0.busy.fetch_sub(self.1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order_and_budget() {
+        // A budget of its own, so tests running alongside can't change it or be slowed by it.
+        let budget = Budget::new();
+        budget.set_threads(4);
+        let items = (0..200).collect::<Vec<u64>>();
+        let nested = budget.map(&items, |i| {
+            assert!(budget.busy.load(Ordering::Relaxed) <= 4);
+            budget.map(&[*i, i + 1], |j| j * 2).iter().sum::<u64>()
+        });
+        assert_eq!(nested, items.iter().map(|i| 4 * i + 2).collect::<Vec<_>>());
+        assert_eq!(budget.busy.load(Ordering::Relaxed), 1);
+    }
+}
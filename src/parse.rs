@@ -0,0 +1,175 @@
+//! Errors that point at the spot in a day's input where parsing went wrong.
+//!
+//! Every input is one leaked `&'static str` and parsers work on slices of it, so a failing slice
+//! is enough to recover the line and column afterwards. Parsers report where they stopped with
+//! [`error_at`], by failing inside [`lines`], or by returning a nom error through `?`. The day
+//! registry then turns whatever comes back into a [`ParseError`].
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Result;
+
+type NomError = nom::Err<nom::error::Error<&'static str>>;
+
+/// A failure at `at`, the remainder of the input from the offending spot onwards.
+#[derive(Debug)]
+struct Located {
+    at: &'static str,
+    message: String,
+}
+
+impl fmt::Display for Located {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Located {}
+
+/// An error pointing at `at`, which must be a slice of the input being parsed.
+pub fn error_at(at: &'static str, message: impl fmt::Display) -> anyhow::Error {
+    Located {
+        at,
+        message: message.to_string(),
+    }
+    .into()
+}
+
+/// Parses `text` with its `FromStr`, pointing any failure at `text`.
+pub fn parse_at<T: FromStr>(text: &'static str) -> Result<T>
+where
+    T::Err: fmt::Display,
+{
+    text.parse()
+        .map_err(|err| error_at(text, format!("Bad value {text:?}: {err}")))
+}
+
+fn location(err: &anyhow::Error) -> Option<&'static str> {
+    if let Some(located) = err.downcast_ref::<Located>() {
+        return Some(located.at);
+    }
+    match err.downcast_ref::<NomError>()? {
+        nom::Err::Error(e) | nom::Err::Failure(e) => Some(e.input),
+        nom::Err::Incomplete(_) => None,
+    }
+}
+
+fn message(err: &anyhow::Error) -> String {
+    match err.downcast_ref::<NomError>() {
+        Some(nom::Err::Error(e) | nom::Err::Failure(e)) => match e.code {
+            nom::error::ErrorKind::Eof => "Unexpected trailing input".to_string(),
+            code => format!("Unexpected input ({})", code.description()),
+        },
+        _ => format!("{err:#}"),
+    }
+}
+
+/// Parses each line of `input` with `parse`. Failures that don't say where they happened are
+/// pinned to the start of their line.
+pub fn lines<T>(input: &'static str, mut parse: impl FnMut(&'static str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .map(|line| {
+            parse(line).map_err(|err| match location(&err) {
+                Some(_) => err,
+                None => error_at(line, message(&err)),
+            })
+        })
+        .collect()
+}
+
+/// A parse failure in a day's input, with enough context to print the offending line.
+#[derive(Debug)]
+pub struct ParseError {
+    pub day: u8,
+    /// One-based line and column, when the parser said where it failed.
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    /// The whole offending line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(day: u8, input: &'static str, err: &anyhow::Error) -> Self {
+        let message = message(err);
+        let offset = location(err)
+            .map(|at| at.as_ptr() as usize)
+            .and_then(|at| at.checked_sub(input.as_ptr() as usize))
+            .filter(|offset| *offset <= input.len());
+        let Some(offset) = offset else {
+            return ParseError {
+                day,
+                line: None,
+                column: None,
+                text: String::new(),
+                message,
+            };
+        };
+        let start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+        ParseError {
+            day,
+            line: Some(input[..offset].matches('\n').count() + 1),
+            column: Some(input[start..offset].chars().count() + 1),
+            text: input[start..end].trim_end_matches('\r').to_string(),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (Some(line), Some(column)) = (self.line, self.column) else {
+            return write!(f, "Day {:02}: {}", self.day, self.message);
+        };
+        let gutter = line.to_string().len();
+        writeln!(f, "Day {:02}, line {line}, column {column}: {}", self.day, self.message)?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{line} | {}", self.text)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+    use nom::character::complete::digit1;
+
+    use super::*;
+
+    const INPUT: &str = "12 34\n56 x8\n";
+
+    fn parse(input: &'static str) -> Result<Vec<Vec<u32>>> {
+        lines(input, |line| {
+            line.split(' ')
+                .map(|word| {
+                    let (_, digits) = digit1::<_, nom::error::Error<_>>(word)?;
+                    Ok(digits.parse()?)
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn points_at_the_failing_column() {
+        let err = ParseError::new(4, INPUT, &parse(INPUT).unwrap_err());
+        assert_eq!((err.line, err.column), (Some(2), Some(4)));
+        assert_eq!(err.text, "56 x8");
+        assert_eq!(
+            err.to_string(),
+            "Day 04, line 2, column 4: Unexpected input (Digit)\n  |\n2 | 56 x8\n  |    ^"
+        );
+    }
+
+    #[test]
+    fn unlocated_errors_point_at_their_line() {
+        let err = lines(INPUT, |line| -> Result<()> { bail!("Bad line {line}") }).unwrap_err();
+        let err = ParseError::new(4, INPUT, &err);
+        assert_eq!((err.line, err.column), (Some(1), Some(1)));
+        assert_eq!(err.message, "Bad line 12 34");
+    }
+}
@@ -0,0 +1,154 @@
+//! `aoc new`: starts a day from `src/days/template.rs` and registers it with the runner.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::client::DEFAULT_BASE_URL;
+use crate::input;
+
+const TEMPLATE: &str = include_str!("days/template.rs");
+
+/// Compiled in tests, so the template keeps up with the `Solution` trait. Rendering only
+/// changes names and numbers in it, which the tests check.
+#[cfg(test)]
+#[path = "days/template.rs"]
+mod template;
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The template with its day and year filled in.
+pub fn render(day: u8, year: u16) -> String {
+    TEMPLATE
+        .replace(
+            "https://adventofcode.com/2023/day/0",
+            &format!("{DEFAULT_BASE_URL}/{year}/day/{day}"),
+        )
+        .replace("Day00", &format!("Day{day:02}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+}
+
+fn insert_sorted(lines: &mut Vec<String>, day: u8, line: String, pattern: impl Fn(&str) -> Option<u8>) -> Result<()> {
+    let existing = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| pattern(l).map(|d| (i, d)))
+        .collect::<Vec<_>>();
+    let Some(&(last, _)) = existing.last() else {
+        bail!("Could not find where to add {line:?}");
+    };
+    let at = existing
+        .iter()
+        .find(|(_, d)| *d > day)
+        .map_or(last + 1, |(i, _)| *i);
+    lines.insert(at, line);
+    Ok(())
+}
+
+fn day_number(line: &str, prefix: &str) -> Option<u8> {
+    line.trim().strip_prefix(prefix)?.get(..2)?.parse().ok()
+}
+
+/// Adds `pub mod dayNN;` and its entry in `ALL` to the text of `src/days/mod.rs`, keeping both
+/// lists in day order.
+pub fn register(registry: &str, day: u8) -> Result<String> {
+    let module = format!("day{day:02}");
+    if registry.lines().any(|l| l.trim() == format!("pub mod {module};")) {
+        bail!("Day {day} is already registered");
+    }
+    let mut lines = registry.lines().map(String::from).collect::<Vec<_>>();
+    insert_sorted(&mut lines, day, format!("pub mod {module};"), |l| {
+        day_number(l, "pub mod day")
+    })?;
+    insert_sorted(&mut lines, day, format!("    Day::of::<{module}::Day{day:02}>(),"), |l| {
+        day_number(l, "Day::of::<day")
+    })?;
+    Ok(lines.join("\n") + "\n")
+}
+
+fn create_empty(path: &Path, created: &mut Vec<PathBuf>) -> Result<()> {
+    if !path.exists() {
+        fs::create_dir_all(input::inputs_dir())?;
+        fs::write(path, "").with_context(|| format!("Creating {}", path.display()))?;
+        created.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+/// Writes the day's module, registers it, creates empty input and example files and adds a row
+/// for the example's part one answer to `tests/answers.tsv`, which the regression tests skip
+/// until the example file has content. Returns every file it touched.
+pub fn create(day: u8, year: u16) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("There is no day {day}");
+    }
+    let days_dir = root().join("src/days");
+    let module = days_dir.join(format!("day{day:02}.rs"));
+    if module.exists() {
+        bail!("{} already exists", module.display());
+    }
+    let registry_path = days_dir.join("mod.rs");
+    let registry = fs::read_to_string(&registry_path)
+        .with_context(|| format!("Reading {}", registry_path.display()))?;
+    let registry = register(&registry, day)?;
+
+    fs::write(&module, render(day, year)).with_context(|| format!("Writing {}", module.display()))?;
+    fs::write(&registry_path, registry).with_context(|| format!("Writing {}", registry_path.display()))?;
+    let mut touched = vec![module, registry_path];
+
+    create_empty(&input::real_path(day, year), &mut touched)?;
+    let example = input::example_path(day, year);
+    create_empty(&example, &mut touched)?;
+
+    let answers = root().join("tests/answers.tsv");
+    let name = example.file_name().unwrap_or_default().to_string_lossy();
+    let mut file = OpenOptions::new()
+        .append(true)
+        .open(&answers)
+        .with_context(|| format!("Opening {}", answers.display()))?;
+    writeln!(file, "# Day {day}: paste the example into {name} and replace the ? with its answer")?;
+    writeln!(file, "{day}\t1\t{name}\t?")?;
+    touched.push(answers);
+
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::Day;
+
+    #[test]
+    fn render_fills_in_the_day() {
+        let text = render(21, 2023);
+        assert!(text.contains("pub struct Day21;"));
+        assert!(text.contains("const DAY: u8 = 21;"));
+        assert!(text.contains("https://adventofcode.com/2023/day/21"));
+        assert!(!text.contains("Day00"));
+        assert!(!text.contains("DAY: u8 = 0;"));
+    }
+
+    #[test]
+    fn template_runs() {
+        let day = Day::of::<template::Day00>();
+        let parsed = (day.parse)("a\nb").unwrap();
+        assert_eq!((day.part_one)(parsed.as_ref()).unwrap(), "0");
+        assert_eq!((day.part_two)(parsed.as_ref()).unwrap(), "0");
+    }
+
+    #[test]
+    fn register_keeps_day_order() {
+        let registry = "pub mod day01;\npub mod day03;\n\npub const ALL: &[Day] = &[\n    Day::of::<day01::Day01>(),\n    Day::of::<day03::Day03>(),\n];\n";
+        let updated = register(registry, 2).unwrap();
+        assert_eq!(
+            updated,
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\npub const ALL: &[Day] = &[\n    Day::of::<day01::Day01>(),\n    Day::of::<day02::Day02>(),\n    Day::of::<day03::Day03>(),\n];\n"
+        );
+        assert!(register(&updated, 2).is_err());
+        assert!(register(registry, 4).unwrap().contains("pub mod day03;\npub mod day04;\n"));
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+
+use crate::image::Image;
+
+/// A day's puzzle. `parse` turns the raw input into whatever both parts work from, so the
+/// parts only ever see structured data.
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed>;
+
+    fn part_one(parsed: &Self::Parsed) -> Result<Self::PartOne>;
+
+    fn part_two(parsed: &Self::Parsed) -> Result<Self::PartTwo>;
+
+    /// A picture of the puzzle, for `aoc render`. Only days with something worth looking at
+    /// draw one.
+    fn render(_parsed: &Self::Parsed) -> Result<Image> {
+        bail!("Day {} has no image to render", Self::DAY)
+    }
+}
@@ -0,0 +1,34 @@
+//! Diagnostic output from the days. Each day logs under its own module, so
+//! `--trace day05=debug,day19=trace` turns on just those, and nothing is printed unless asked for.
+
+use anyhow::{Context, Result};
+use tracing_subscriber::EnvFilter;
+
+/// Expands `dayNN` targets to the day's module path; anything else is passed through as an
+/// ordinary `EnvFilter` directive, so a bare level like `debug` applies to every day.
+pub fn filter(spec: &str) -> Result<EnvFilter> {
+    let directives = spec
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|d| {
+            if d.starts_with("day") {
+                format!("{}::days::{d}", env!("CARGO_CRATE_NAME"))
+            } else {
+                d.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    EnvFilter::try_new(&directives).with_context(|| format!("Bad trace filter {spec:?}"))
+}
+
+/// Sends traces matching `spec` to stderr, leaving stdout to the answers.
+pub fn init(spec: &str) -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(filter(spec)?)
+        .with_writer(std::io::stderr)
+        .without_time()
+        .init();
+    Ok(())
+}
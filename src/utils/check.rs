@@ -0,0 +1,128 @@
+//! Property checks for the days' parsers: generated puzzle records must parse back to what was
+//! printed, and damaged ones must come back as errors rather than panics.
+
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+use super::rng::Rng;
+use crate::solution::Solution;
+
+/// How many generated inputs each property is checked against.
+pub const CASES: u64 = 200;
+
+/// Characters that no puzzle format uses anywhere.
+const FOREIGN: char = '@';
+
+/// Checks `property` against `CASES` values from `generate`, reporting the seed and value of the
+/// first failure.
+pub fn for_all<T: Debug>(mut generate: impl FnMut(&mut Rng) -> T, mut property: impl FnMut(&T)) {
+    for seed in 0..CASES {
+        let value = generate(&mut Rng::new(seed));
+        if let Err(err) = panic::catch_unwind(AssertUnwindSafe(|| property(&value))) {
+            eprintln!("Property failed for seed {seed} on {value:#?}");
+            panic::resume_unwind(err);
+        }
+    }
+}
+
+/// `text` with one random character deleted, duplicated or swapped with its neighbour, or with a
+/// random line cut short. The result may or may not still be valid.
+pub fn mutate(rng: &mut Rng, text: &str) -> String {
+    let mut chars = text.chars().collect::<Vec<_>>();
+    if chars.len() < 2 {
+        return String::new();
+    }
+    let i = rng.index(chars.len() - 1);
+    match rng.index(4) {
+        0 => {
+            chars.remove(i);
+        }
+        1 => chars.insert(i, chars[i]),
+        2 => chars.swap(i, i + 1),
+        _ => {
+            let end = chars[i..].iter().position(|c| *c == '\n').map_or(chars.len(), |n| i + n);
+            chars.drain(i..end);
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// `text` with one character other than a line break replaced by one no input may contain, or
+/// with that character added to the end when there is nothing but line breaks to replace.
+pub fn corrupt(rng: &mut Rng, text: &str) -> String {
+    let positions = text.char_indices().filter(|(_, c)| *c != '\n').map(|(i, _)| i).collect::<Vec<_>>();
+    if positions.is_empty() {
+        return format!("{text}{FOREIGN}");
+    }
+    let at = *rng.pick(&positions);
+    let mut corrupted = text.to_string();
+    let len = corrupted[at..].chars().next().map_or(0, char::len_utf8);
+    corrupted.replace_range(at..at + len, &FOREIGN.to_string());
+    corrupted
+}
+
+fn leak(text: &str) -> &'static str {
+    text.to_string().leak()
+}
+
+/// Checks that `S::parse` reads back whatever `generate` makes and `print` writes out, never
+/// panics on mutated text, and rejects corrupted text.
+pub fn parser<S: Solution>(mut generate: impl FnMut(&mut Rng) -> S::Parsed, print: impl Fn(&S::Parsed) -> String)
+where
+    S::Parsed: PartialEq + Debug,
+{
+    for_all(&mut generate, |parsed| {
+        let text = print(parsed);
+        match S::parse(leak(&text)) {
+            Ok(reparsed) => assert_eq!(&reparsed, parsed, "reparsing {text:?}"),
+            Err(err) => panic!("failed to parse {text:?}: {err:#}"),
+        }
+    });
+    for_all(
+        |rng| {
+            let text = print(&generate(rng));
+            (mutate(rng, &text), corrupt(rng, &text))
+        },
+        |(mutated, corrupted)| {
+            let _ = S::parse(leak(mutated));
+            assert!(S::parse(leak(corrupted)).is_err(), "parsed corrupted {corrupted:?}");
+        },
+    );
+}
+
+/// A `#[test]` per day, checking its parser with the generator and `print` from its test
+/// module.
+macro_rules! parser_tests {
+    ($($day:ident::$solution:ident($generate:ident),)*) => {
+        $(
+            #[test]
+            fn $day() {
+                parser::<days::$day::$solution>(days::$day::tests::$generate, |parsed| days::$day::tests::print(parsed));
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    parser_tests! {
+        day02::Day02(games),
+        day04::Day04(games),
+        day09::Day09(histories),
+        day12::Day12(records),
+        day18::Day18(plan),
+        day19::Day19(system),
+        day20::Day20(modules),
+    }
+
+    #[test]
+    fn corrupts_anything() {
+        let mut rng = Rng::new(0);
+        assert_eq!(corrupt(&mut rng, ""), "@");
+        assert_eq!(corrupt(&mut rng, "\n\n"), "\n\n@");
+        assert_eq!(corrupt(&mut rng, "a\n"), "@\n");
+    }
+}
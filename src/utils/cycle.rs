@@ -0,0 +1,96 @@
+//! Finding where an iterated simulation starts repeating, so step a billion can be read off
+//! without running a billion steps.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The states a simulation passes through up to its first repeat. From `start` on they repeat
+/// every `length` steps.
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    /// The state after each step, from step 0 (the initial state) to just before the repeat.
+    pub states: Vec<S>,
+    pub start: usize,
+    pub length: usize,
+}
+
+impl<S> Cycle<S> {
+    /// Runs `step` from `initial` until a state with an already seen `key` comes up. States with
+    /// equal keys must go on to behave the same.
+    pub fn find<K: Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S, mut key: impl FnMut(&S) -> K) -> Self {
+        let mut seen = HashMap::from([(key(&initial), 0)]);
+        let mut states = vec![initial];
+        loop {
+            let next = step(states.last().expect("starts with the initial state"));
+            if let Some(&start) = seen.get(&key(&next)) {
+                let length = states.len() - start;
+                return Cycle { states, start, length };
+            }
+            seen.insert(key(&next), states.len());
+            states.push(next);
+        }
+    }
+
+    /// Which of the recorded states the simulation is in after `n` steps.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// The state after `n` steps.
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.index(n)]
+    }
+}
+
+impl<S: Clone + Hash + Eq> Cycle<S> {
+    /// [`Cycle::find`] keyed on the whole state.
+    pub fn of(initial: S, step: impl FnMut(&S) -> S) -> Self {
+        Self::find(initial, step, S::clone)
+    }
+}
+
+/// The offset and period of events seen at the given steps, if they are evenly spaced. At least
+/// two events are needed to tell.
+pub fn period(steps: &[u64]) -> Option<(u64, u64)> {
+    let (&first, rest) = steps.split_first()?;
+    let length = rest.first()?.checked_sub(first)?;
+    let even = steps.windows(2).all(|w| w[1].checked_sub(w[0]) == Some(length));
+    (even && length > 0).then_some((first, length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_ahead() {
+        let step = |x: &u64| (x * x + 1) % 1009;
+        let cycle = Cycle::of(2, step);
+        assert_eq!(cycle.states.len(), cycle.start + cycle.length);
+        let mut x = 2;
+        for n in 0..500 {
+            assert_eq!(*cycle.nth(n), x, "step {n}");
+            x = step(&x);
+        }
+    }
+
+    #[test]
+    fn keys_can_ignore_part_of_the_state() {
+        // Counts steps alongside a value that goes 7, 2, 0, 1, 2, ... so is (n + 1) % 3 from step 1.
+        let cycle = Cycle::find((7, 0), |&(x, n)| ((x + 1) % 3, n + 1), |&(x, _)| x);
+        assert_eq!((cycle.start, cycle.length), (1, 3));
+        assert_eq!(cycle.nth(1_000_000_000).0, (1_000_000_000 + 1) % 3);
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(period(&[3, 7, 11]), Some((3, 4)));
+        assert_eq!(period(&[3, 7, 12]), None);
+        assert_eq!(period(&[3]), None);
+        assert_eq!(period(&[3, 3]), None);
+    }
+}
@@ -0,0 +1,173 @@
+use std::ops::Add;
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use glam::{I64Vec2, IVec2};
+
+/// A compass direction on a grid where `y` grows downwards, so north is `(0, -1)`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// A mirror set at 45 degrees, named for the character that draws it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Mirror {
+    /// `/`
+    Slash,
+    /// `\`
+    Backslash,
+}
+
+impl Dir {
+    /// Clockwise from north.
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    pub fn turn_left(self) -> Dir {
+        match self {
+            Dir::North => Dir::West,
+            Dir::East => Dir::North,
+            Dir::South => Dir::East,
+            Dir::West => Dir::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Dir {
+        match self {
+            Dir::North => Dir::East,
+            Dir::East => Dir::South,
+            Dir::South => Dir::West,
+            Dir::West => Dir::North,
+        }
+    }
+
+    pub fn opposite(self) -> Dir {
+        self.turn_left().turn_left()
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir::North | Dir::South)
+    }
+
+    /// The direction a beam travelling this way leaves `mirror` in.
+    pub fn reflect(self, mirror: Mirror) -> Dir {
+        match (mirror, self.is_vertical()) {
+            (Mirror::Slash, true) | (Mirror::Backslash, false) => self.turn_right(),
+            (Mirror::Slash, false) | (Mirror::Backslash, true) => self.turn_left(),
+        }
+    }
+
+    pub fn to_ivec2(self) -> IVec2 {
+        match self {
+            Dir::North => IVec2::NEG_Y,
+            Dir::East => IVec2::X,
+            Dir::South => IVec2::Y,
+            Dir::West => IVec2::NEG_X,
+        }
+    }
+
+    pub fn to_i64vec2(self) -> I64Vec2 {
+        self.to_ivec2().as_i64vec2()
+    }
+}
+
+/// Accepts `U/D/L/R`, `N/S/E/W` and the arrows `^ v < >`.
+impl TryFrom<char> for Dir {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        Ok(match c {
+            'U' | 'N' | '^' => Dir::North,
+            'R' | 'E' | '>' => Dir::East,
+            'D' | 'S' | 'v' => Dir::South,
+            'L' | 'W' | '<' => Dir::West,
+            _ => bail!("Bad direction {c}"),
+        })
+    }
+}
+
+impl FromStr for Dir {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir::try_from(c),
+            _ => bail!("Bad direction {s}"),
+        }
+    }
+}
+
+impl TryFrom<char> for Mirror {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        Ok(match c {
+            '/' => Mirror::Slash,
+            '\\' => Mirror::Backslash,
+            _ => bail!("Bad mirror {c}"),
+        })
+    }
+}
+
+impl From<Dir> for IVec2 {
+    fn from(dir: Dir) -> Self {
+        dir.to_ivec2()
+    }
+}
+
+impl From<Dir> for I64Vec2 {
+    fn from(dir: Dir) -> Self {
+        dir.to_i64vec2()
+    }
+}
+
+impl Add<Dir> for IVec2 {
+    type Output = IVec2;
+
+    fn add(self, dir: Dir) -> IVec2 {
+        self + dir.to_ivec2()
+    }
+}
+
+impl Add<Dir> for I64Vec2 {
+    type Output = I64Vec2;
+
+    fn add(self, dir: Dir) -> I64Vec2 {
+        self + dir.to_i64vec2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.opposite().to_ivec2(), -dir.to_ivec2());
+        }
+        assert_eq!(Dir::North.turn_right(), Dir::East);
+    }
+
+    #[test]
+    fn reflections() {
+        assert_eq!(Dir::East.reflect(Mirror::Slash), Dir::North);
+        assert_eq!(Dir::North.reflect(Mirror::Slash), Dir::East);
+        assert_eq!(Dir::East.reflect(Mirror::Backslash), Dir::South);
+        assert_eq!(Dir::North.reflect(Mirror::Backslash), Dir::West);
+    }
+
+    #[test]
+    fn parsing() {
+        for (chars, dir) in [("UN^", Dir::North), ("RE>", Dir::East), ("DSv", Dir::South), ("LW<", Dir::West)] {
+            assert!(chars.chars().all(|c| Dir::try_from(c).unwrap() == dir));
+        }
+        assert!("X".parse::<Dir>().is_err());
+        assert!("UD".parse::<Dir>().is_err());
+    }
+}
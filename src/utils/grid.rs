@@ -0,0 +1,253 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use anyhow::{bail, Result};
+use glam::IVec2;
+
+use super::Dir;
+use crate::parse::error_at;
+
+/// A rectangular grid of cells, addressed by `IVec2` points with `x` as the column and `y` as
+/// the row, so `(0, 0)` is the top-left cell of the puzzle text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+const DIAGONAL: [IVec2; 4] = [IVec2::new(1, -1), IVec2::new(1, 1), IVec2::new(-1, 1), IVec2::new(-1, -1)];
+
+impl<T> Grid<T> {
+    /// Reads one row per line, turning each character into a cell with `cell`. Every line must
+    /// be the same length.
+    pub fn parse(input: &'static str, mut cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (row, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).map_err(|err| error_at(&line[i..], format!("{err:#}")))?);
+            }
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(error_at(line, format!("Row {row} is {len} cells wide, expected {width}")));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid { cells, width, height }),
+            _ => bail!("Grid is empty"),
+        }
+    }
+
+    /// Builds a grid from its cells in row-major order.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Result<Self> {
+        if width == 0 || cells.is_empty() || cells.len() % width != 0 {
+            bail!("{} cells do not make rows of width {width}", cells.len());
+        }
+        let height = cells.len() / width;
+        Ok(Grid { cells, width, height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: IVec2) -> bool {
+        point.x >= 0 && point.y >= 0 && (point.x as usize) < self.width && (point.y as usize) < self.height
+    }
+
+    fn offset(&self, point: IVec2) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    pub fn get(&self, point: IVec2) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: IVec2) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = IVec2> {
+        let width = self.width as i32;
+        (0..self.cells.len() as i32).map(move |i| IVec2::new(i % width, i / width))
+    }
+
+    /// Every cell alongside its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point, row by row, whose cell matches.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<IVec2> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(point, _)| point)
+    }
+
+    /// The points above, right of, below and left of `point` that lie inside the grid.
+    pub fn neighbours(&self, point: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        Dir::ALL.into_iter().map(move |d| point + d).filter(|p| self.contains(*p))
+    }
+
+    /// Like `neighbours`, but including the four diagonals.
+    pub fn neighbours8(&self, point: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        Dir::ALL
+            .map(IVec2::from)
+            .into_iter()
+            .chain(DIAGONAL)
+            .map(move |d| point + d)
+            .filter(|p| self.contains(*p))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Swaps rows and columns, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flat_map(|col| col.cloned()).collect();
+        Grid {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// Turns the grid a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = self.columns().flat_map(|col| col.cloned().collect::<Vec<_>>().into_iter().rev()).collect();
+        Grid {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// Turns the grid a quarter turn anticlockwise, so the top row becomes the left column.
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let cells = (0..self.width).rev().flat_map(|x| self.column(x).cloned()).collect();
+        Grid {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: IVec2) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    fn index_mut(&mut self, point: IVec2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is outside a {width}x{height} grid"))
+    }
+}
+
+/// Writes one line per row with each cell's own `Display`, so a grid of cells that print as the
+/// characters they were parsed from reads back to the same grid.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "abc\ndef";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(TEXT, Ok).unwrap()
+    }
+
+    #[test]
+    fn display_round_trips() {
+        assert_eq!(grid().to_string(), TEXT);
+        assert_eq!(Grid::parse(grid().to_string().leak(), Ok).unwrap(), grid());
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert!(Grid::parse("abc\nde", Ok).is_err());
+        assert!(Grid::parse("", Ok).is_err());
+    }
+
+    #[test]
+    fn bounds_checked_access() {
+        let grid = grid();
+        assert_eq!(grid.get(IVec2::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(IVec2::new(3, 0)), None);
+        assert_eq!(grid.get(IVec2::new(0, -1)), None);
+        assert_eq!(grid.neighbours(IVec2::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(IVec2::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn views_and_rotations() {
+        let grid = grid();
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
@@ -0,0 +1,253 @@
+//! Half-open integer intervals and sets of them, for days that push whole ranges of values
+//! through rules instead of one value at a time.
+
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+
+/// The integers from `start` up to but not including `end`. Every empty interval is stored as
+/// [`Interval::EMPTY`], so empty intervals compare equal.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    pub const EMPTY: Interval = Interval { start: 0, end: 0 };
+
+    pub fn new(start: i64, end: i64) -> Self {
+        if end <= start {
+            Self::EMPTY
+        } else {
+            Interval { start, end }
+        }
+    }
+
+    pub fn from_len(start: i64, len: i64) -> Self {
+        Self::new(start, start.saturating_add(len))
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    pub fn len(&self) -> u64 {
+        self.end.abs_diff(self.start)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersect(&self, other: &Interval) -> Interval {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The values below `at`, and the rest.
+    pub fn split_at(&self, at: i64) -> (Interval, Interval) {
+        let at = at.clamp(self.start, self.end);
+        (Self::new(self.start, at), Self::new(at, self.end))
+    }
+
+    /// The values below `other` and those above it. Either may be empty.
+    pub fn difference(&self, other: &Interval) -> [Interval; 2] {
+        if other.is_empty() {
+            return [*self, Self::EMPTY];
+        }
+        [self.split_at(other.start).0, self.split_at(other.end).1]
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        if self.is_empty() {
+            return *self;
+        }
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl fmt::Debug for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl From<RangeInclusive<i64>> for Interval {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        Self::new(*range.start(), range.end().saturating_add(1))
+    }
+}
+
+/// A set of integers kept as sorted intervals that neither overlap nor touch, so two sets with
+/// the same members compare equal.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `interval`, merging it with any it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let mut merged = interval;
+        self.intervals.retain(|other| {
+            // Anything touching the merged interval touches `interval` itself, since the
+            // intervals already here don't touch each other.
+            let touches = other.start <= merged.end && merged.start <= other.end;
+            if touches {
+                merged = Interval::new(merged.start.min(other.start), merged.end.max(other.end));
+            }
+            !touches
+        });
+        let at = self.intervals.partition_point(|other| other.start < merged.start);
+        self.intervals.insert(at, merged);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.intervals.iter().any(|interval| interval.contains(value))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(Interval::start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter()
+            .flat_map(|a| other.iter().map(|b| a.intersect(b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter()
+            .flat_map(|a| {
+                other.iter().fold(vec![*a], |pieces, b| {
+                    pieces.iter().flat_map(|piece| piece.difference(b)).collect()
+                })
+            })
+            .collect()
+    }
+
+    /// The members below `at`, and the rest.
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        let pieces = self.iter().map(|interval| interval.split_at(at));
+        let (below, above): (Vec<_>, Vec<_>) = pieces.unzip();
+        (below.into_iter().collect(), above.into_iter().collect())
+    }
+
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        IntervalSet {
+            intervals: self.intervals.iter().map(|interval| interval.shift(offset)).collect(),
+        }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet::from_iter([interval])
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        iter.into_iter().for_each(|interval| set.insert(interval));
+        set
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|interval| self.insert(interval));
+    }
+}
+
+impl fmt::Debug for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet {
+        ranges.iter().map(|&(start, end)| Interval::new(start, end)).collect()
+    }
+
+    #[test]
+    fn intervals() {
+        let a = Interval::from(1..=10);
+        assert_eq!((a.start(), a.end(), a.len()), (1, 11, 10));
+        assert_eq!(Interval::new(5, 2), Interval::from(7..7));
+        assert_eq!(a.intersect(&(8..20).into()), (8..11).into());
+        assert!(a.intersect(&(11..20).into()).is_empty());
+        assert_eq!(a.split_at(4), ((1..4).into(), (4..11).into()));
+        assert_eq!(a.split_at(-3), (Interval::EMPTY, a));
+        assert_eq!(a.difference(&(3..5).into()), [(1..3).into(), (5..11).into()]);
+        assert_eq!(a.shift(-1), (0..10).into());
+    }
+
+    #[test]
+    fn sets_stay_normalized() {
+        let s = set(&[(5, 8), (0, 2), (2, 3), (7, 9), (4, 4)]);
+        assert_eq!(s.iter().copied().collect::<Vec<_>>(), [(0..3).into(), (5..9).into()]);
+        assert_eq!(s.len(), 7);
+        assert_eq!((s.min(), s.max()), (Some(0), Some(8)));
+        assert_eq!(s, set(&[(0, 1), (1, 3), (5, 9)]));
+        assert!(set(&[]).is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+        assert_eq!(a.split_at(22), (set(&[(0, 10), (20, 22)]), set(&[(22, 30)])));
+        assert_eq!(a.shift(5), set(&[(5, 15), (25, 35)]));
+        assert!(a.contains(25) && !a.contains(15));
+    }
+}
@@ -0,0 +1,89 @@
+//! Caching for recursive solvers. The cache is passed back into the computation for each key, so
+//! a recursive function only has to thread one `&mut Memo` through its calls.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// How often a [`Memo`] answered from its cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lookups = self.hits + self.misses;
+        let rate = if lookups == 0 { 0.0 } else { self.hits as f64 * 100.0 / lookups as f64 };
+        write!(f, "{} hits, {} misses ({rate:.1}% hit rate)", self.hits, self.misses)
+    }
+}
+
+/// Results of a function by argument key.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached value for `key`, or else `compute(self)`, cached. `compute` gets the memo back
+    /// so it can recurse through it.
+    pub fn get(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        memo.get(n, |memo| if n < 2 { n } else { fib(memo, n - 1) + fib(memo, n - 2) })
+    }
+
+    #[test]
+    fn recursion_through_the_memo() {
+        let mut memo = Memo::new();
+        assert_eq!(fib(&mut memo, 90), 2_880_067_194_370_816_120);
+        assert_eq!(memo.len(), 91);
+        // Each n from 3 up asks for n - 2 after computing n - 1 has already cached it.
+        assert_eq!(memo.stats(), MemoStats { hits: 88, misses: 91 });
+        assert_eq!(fib(&mut memo, 50), 12_586_269_025);
+        assert_eq!(memo.stats().hits, 89);
+        assert_eq!(memo.stats().to_string(), "89 hits, 91 misses (49.4% hit rate)");
+    }
+}
@@ -0,0 +1,18 @@
+#[cfg(test)]
+pub mod check;
+pub mod cycle;
+pub mod dir;
+pub mod grid;
+pub mod interval;
+pub mod memo;
+pub mod number;
+pub mod parsers;
+pub mod polygon;
+pub mod rng;
+pub mod search;
+
+pub use self::cycle::Cycle;
+pub use self::dir::{Dir, Mirror};
+pub use self::grid::Grid;
+pub use self::parsers::ws;
+
@@ -0,0 +1,140 @@
+//! Number theory for lining up cycles: gcd, lcm and the Chinese remainder theorem, all of which
+//! report overflow instead of wrapping.
+
+use anyhow::{Context, Result};
+use num::Integer;
+
+/// `(g, x, y)` with `g = gcd(a, b) = a * x + b * y` and `g >= 0`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// The lcm of `a` and `b`, or `None` if it doesn't fit in a `u64`.
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / a.gcd(&b)).checked_mul(b)
+}
+
+/// The lcm of all of `values`, failing if it doesn't fit in a `u64`.
+pub fn lcm(values: impl IntoIterator<Item = u64>) -> Result<u64> {
+    values.into_iter().try_fold(1, |acc, value| {
+        checked_lcm(acc, value).with_context(|| format!("lcm of {acc} and {value} overflows"))
+    })
+}
+
+/// The numbers `residue + k * modulus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Congruence {
+    pub residue: u64,
+    pub modulus: u64,
+}
+
+impl Congruence {
+    pub fn new(residue: u64, modulus: u64) -> Self {
+        Congruence {
+            residue: residue % modulus,
+            modulus,
+        }
+    }
+
+    /// The smallest of the numbers that is at least `n`, if it fits in a `u64`.
+    pub fn first_from(&self, n: u64) -> Option<u64> {
+        if n <= self.residue {
+            return Some(self.residue);
+        }
+        let steps = (n - self.residue).div_ceil(self.modulus);
+        steps.checked_mul(self.modulus)?.checked_add(self.residue)
+    }
+}
+
+fn merge(a: Congruence, b: Congruence) -> Result<Option<Congruence>> {
+    let (g, p, _) = extended_gcd(a.modulus as i128, b.modulus as i128);
+    let diff = b.residue as i128 - a.residue as i128;
+    if diff % g != 0 {
+        return Ok(None);
+    }
+    let modulus = checked_lcm(a.modulus, b.modulus)
+        .with_context(|| format!("lcm of moduli {} and {} overflows", a.modulus, b.modulus))?;
+    // a.residue + a.modulus * t is also b.residue mod b.modulus for t = diff / g * p, which only
+    // matters mod b.modulus / g. Both factors are below 2^64, so their product fits in a u128.
+    let step = (b.modulus / g as u64) as u128;
+    let t = (diff / g).rem_euclid(step as i128) as u128 * p.rem_euclid(step as i128) as u128 % step;
+    let residue = (a.residue as u128 + a.modulus as u128 * t) % modulus as u128;
+    Ok(Some(Congruence::new(residue as u64, modulus)))
+}
+
+/// The numbers that satisfy every one of `congruences`, whose moduli need not be coprime.
+/// `Ok(None)` when no number does; an error if the combined modulus overflows.
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Result<Option<Congruence>> {
+    let mut combined = Congruence::new(0, 1);
+    for congruence in congruences {
+        match merge(combined, congruence)? {
+            Some(merged) => combined = merged,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(combined))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_coefficients() {
+        for (a, b) in [(240, 46), (46, 240), (-12, 18), (7, 0), (0, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, a.gcd(&b), "gcd({a}, {b})");
+            assert_eq!(a * x + b * y, g, "coefficients for ({a}, {b})");
+        }
+    }
+
+    #[test]
+    fn lcm_overflow() {
+        assert_eq!(lcm([4, 6, 10]).unwrap(), 60);
+        assert_eq!(lcm([]).unwrap(), 1);
+        assert!(lcm([u64::MAX, u64::MAX - 1]).is_err());
+    }
+
+    #[test]
+    fn remainders() {
+        let solve = |pairs: &[(u64, u64)]| {
+            crt(pairs.iter().map(|&(r, m)| Congruence::new(r, m)))
+                .unwrap()
+                .map(|c| (c.residue, c.modulus))
+        };
+        assert_eq!(solve(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Non-coprime moduli that agree on their common factor, and ones that don't.
+        assert_eq!(solve(&[(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(solve(&[(1, 4), (2, 6)]), None);
+        assert_eq!(solve(&[]), Some((0, 1)));
+
+        let big = u64::MAX / 3;
+        assert_eq!(solve(&[(big - 1, big), (1, 2)]), Some((big * 2 - 1, big * 2)));
+        assert!(crt([Congruence::new(0, u64::MAX), Congruence::new(0, u64::MAX - 1)]).is_err());
+    }
+
+    #[test]
+    fn first_from() {
+        let c = Congruence::new(3, 7);
+        assert_eq!(c.first_from(0), Some(3));
+        assert_eq!(c.first_from(4), Some(10));
+        assert_eq!(c.first_from(10), Some(10));
+        assert_eq!(Congruence::new(0, u64::MAX / 2).first_from(u64::MAX), None);
+    }
+}
@@ -0,0 +1,153 @@
+//! nom combinators for the shapes that keep turning up in puzzle inputs.
+
+use std::str::FromStr;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{
+    alphanumeric1, char, digit1, line_ending, multispace0, not_line_ending, one_of, space0, space1,
+};
+use nom::combinator::{eof, map_res, opt, recognize, verify};
+use nom::error::{Error, ParseError};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, preceded, separated_pair, terminated};
+use nom::{IResult, Parser};
+
+//  https://github.com/rust-bakery/nom/blob/main/doc/nom_recipes.md#wrapper-combinators-that-eat-whitespace-before-and-after-a-parser
+pub fn ws<'a, F, O, E: ParseError<&'a str>>(inner: F) -> impl Parser<&'a str, O, E>
+    where
+        F: Parser<&'a str, O, E>,
+{
+    delimited(multispace0, inner, multispace0)
+}
+
+/// A run of digits.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// A run of digits with an optional `-` or `+` in front.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// Items on one line separated by spaces or tabs.
+pub fn spaced<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(space1, item)
+}
+
+/// A line of unsigned numbers separated by spaces, however many.
+pub fn numbers<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    spaced(unsigned)(input)
+}
+
+/// `label` and any spaces after it, then `inner`, as in `Time:      7  15   30`.
+pub fn labelled<'a, O, F>(label: &'static str, inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    preceded(pair(tag(label), space0), inner)
+}
+
+/// One item per line.
+pub fn lines<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(line_ending, item)
+}
+
+/// A run of non-empty lines, without the line break after the last of them.
+pub fn block(input: &str) -> IResult<&str, &str> {
+    recognize(separated_list1(
+        line_ending,
+        verify(not_line_ending, |line: &str| !line.is_empty()),
+    ))(input)
+}
+
+/// The line break ending one section and the empty line after it.
+pub fn blank_line(input: &str) -> IResult<&str, &str> {
+    recognize(pair(line_ending, line_ending))(input)
+}
+
+/// Sections separated by blank lines.
+pub fn sections<'a, O, F>(section: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(blank_line, section)
+}
+
+/// `name = (left, right)`.
+pub fn pair_record(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    separated_pair(
+        alphanumeric1,
+        tag(" = "),
+        delimited(
+            char('('),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            char(')'),
+        ),
+    )(input)
+}
+
+/// Runs `parser` over the whole input. Only whitespace may follow it; anything else fails with
+/// `ErrorKind::Eof` at the first leftover character.
+pub fn full<'a, O, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    terminated(parser, pair(multispace0, eof))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::ErrorKind;
+
+    use super::*;
+
+    fn fails_at<O: std::fmt::Debug>(result: IResult<&str, O>, at: &str, code: ErrorKind) {
+        match result {
+            Err(nom::Err::Error(e)) => assert_eq!((e.input, e.code), (at, code)),
+            other => panic!("expected an error at {at:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(signed::<i64>("-12 3"), Ok((" 3", -12)));
+        assert_eq!(signed::<i64>("+7"), Ok(("", 7)));
+        assert_eq!(unsigned::<u64>("42x"), Ok(("x", 42)));
+        assert!(unsigned::<u64>("-1").is_err());
+        assert!(unsigned::<u8>("300").is_err());
+    }
+
+    #[test]
+    fn number_lists() {
+        assert_eq!(numbers::<u64>("1  2\t3\n4"), Ok(("\n4", vec![1, 2, 3])));
+        assert_eq!(spaced(signed::<i32>)("0 -1 2"), Ok(("", vec![0, -1, 2])));
+        assert_eq!(labelled("Time:", numbers::<u32>)("Time:      7  15   30"), Ok(("", vec![7, 15, 30])));
+    }
+
+    #[test]
+    fn sections_and_blocks() {
+        let text = "a\nb\n\nc\n\nd e\n";
+        assert_eq!(sections(block)(text), Ok(("\n", vec!["a\nb", "c", "d e"])));
+        assert_eq!(sections(lines(numbers::<u8>))("1 2\n3\n\n4"), Ok(("", vec![vec![vec![1, 2], vec![3]], vec![vec![4]]])));
+    }
+
+    #[test]
+    fn records() {
+        assert_eq!(pair_record("AAA = (BBB, CCC)"), Ok(("", ("AAA", ("BBB", "CCC")))));
+        assert!(pair_record("AAA = (BBB CCC)").is_err());
+    }
+
+    #[test]
+    fn full_input() {
+        assert_eq!(full(numbers::<u8>)("1 2\n"), Ok(("", vec![1, 2])));
+        fails_at(full(numbers::<u8>)("1 2 x\n"), "x\n", ErrorKind::Eof);
+        fails_at(full(lines(numbers::<u8>))("1\n2\n\nx"), "x", ErrorKind::Eof);
+    }
+}
@@ -0,0 +1,148 @@
+//! Simple polygons with integer vertices: area by the shoelace formula, lattice point counts by
+//! Pick's theorem, and point-in-polygon tests.
+
+use glam::I64Vec2;
+use num::Integer;
+
+/// A closed polygon through `vertices` in order, with an edge from the last back to the first.
+/// Edges may not cross each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<I64Vec2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<I64Vec2>) -> Self {
+        Polygon { vertices }
+    }
+
+    /// The polygon traced by starting at `start` and taking each `(direction, count)` step in
+    /// turn, as in a dig plan.
+    pub fn from_steps(start: I64Vec2, steps: impl IntoIterator<Item = (I64Vec2, i64)>) -> Self {
+        let mut vertices = vec![start];
+        for (dir, count) in steps {
+            let last = *vertices.last().expect("starts with a vertex");
+            vertices.push(last + dir * count);
+        }
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[I64Vec2] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (I64Vec2, I64Vec2)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the signed area, which is always a whole number. Positive when the vertices run
+    /// counterclockwise with y pointing up, so clockwise on screen where y points down.
+    pub fn double_signed_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.x * b.y - a.y * b.x).sum()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.double_signed_area().abs() as f64 / 2.0
+    }
+
+    /// How many lattice points lie on the edges.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| {
+                let d = (b - a).abs();
+                d.x.gcd(&d.y)
+            })
+            .sum()
+    }
+
+    /// How many lattice points lie strictly inside, by Pick's theorem: A = I + B / 2 - 1.
+    pub fn interior_points(&self) -> i64 {
+        (self.double_signed_area().abs() - self.boundary_points()) / 2 + 1
+    }
+
+    pub fn on_boundary(&self, p: I64Vec2) -> bool {
+        self.edges().any(|(a, b)| {
+            let (ab, ap) = (b - a, p - a);
+            ab.perp_dot(ap) == 0 && ap.dot(ab) >= 0 && ap.dot(ab) <= ab.dot(ab)
+        })
+    }
+
+    /// Whether `p` is strictly inside, by counting the edges a ray from `p` towards +x crosses.
+    pub fn contains(&self, p: I64Vec2) -> bool {
+        if self.on_boundary(p) {
+            return false;
+        }
+        let crossings = self
+            .edges()
+            .filter(|(a, b)| (a.y > p.y) != (b.y > p.y))
+            .filter(|(a, b)| {
+                // Whether p is left of where the edge crosses its row, without dividing.
+                let (lhs, rhs) = ((p.x - a.x) * (b.y - a.y), (p.y - a.y) * (b.x - a.x));
+                if b.y > a.y {
+                    lhs < rhs
+                } else {
+                    lhs > rhs
+                }
+            })
+            .count();
+        crossings % 2 == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(points: &[(i64, i64)]) -> Polygon {
+        Polygon::new(points.iter().map(|&(x, y)| I64Vec2::new(x, y)).collect())
+    }
+
+    #[test]
+    fn square() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(square.double_signed_area(), 32);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        let reversed = Polygon::new(square.vertices().iter().rev().copied().collect());
+        assert_eq!(reversed.double_signed_area(), -32);
+        assert_eq!(reversed.interior_points(), 9);
+    }
+
+    #[test]
+    fn triangle_lattice_points() {
+        let triangle = polygon(&[(0, 0), (6, 0), (0, 3)]);
+        assert_eq!(triangle.area(), 9.0);
+        assert_eq!(triangle.boundary_points(), 6 + 3 + 3);
+        assert_eq!(triangle.interior_points(), 4);
+        let inside = (0..=6)
+            .flat_map(|x| (0..=3).map(move |y| I64Vec2::new(x, y)))
+            .filter(|p| triangle.contains(*p))
+            .count();
+        assert_eq!(inside, 4);
+    }
+
+    #[test]
+    fn point_in_polygon() {
+        // An L shape, so some rays pass through the notch.
+        let l = polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+        assert!(l.contains(I64Vec2::new(1, 1)));
+        assert!(l.contains(I64Vec2::new(1, 3)));
+        assert!(!l.contains(I64Vec2::new(3, 3)));
+        assert!(!l.contains(I64Vec2::new(2, 3)));
+        assert!(l.on_boundary(I64Vec2::new(3, 2)));
+        assert!(!l.contains(I64Vec2::new(-1, 1)));
+    }
+
+    #[test]
+    fn steps() {
+        let east = I64Vec2::X;
+        let south = I64Vec2::Y;
+        let p = Polygon::from_steps(I64Vec2::ZERO, [(east, 3), (south, 2), (-east, 3), (-south, 2)]);
+        assert_eq!(p.vertices().len(), 4);
+        assert_eq!(p.interior_points() + p.boundary_points(), 12);
+    }
+}
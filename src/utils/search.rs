@@ -0,0 +1,216 @@
+//! Breadth-first, Dijkstra and A* search over any hashable state.
+//!
+//! States are stored once in an arena alongside the index of the state they were reached from,
+//! so the path to a goal can be walked back without cloning whole paths around.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use num::Zero;
+
+/// The cheapest way to a goal that a search found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    /// Every state from the start to the goal, both included.
+    pub path: Vec<S>,
+    /// How many states were expanded before the goal turned up.
+    pub visited: usize,
+}
+
+fn path<S: Clone>(nodes: &[(S, Option<usize>)], mut index: usize) -> Vec<S> {
+    let mut path = vec![nodes[index].0.clone()];
+    while let Some(parent) = nodes[index].1 {
+        path.push(nodes[parent].0.clone());
+        index = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Fewest steps from any of `starts` to a state where `is_goal` holds, when every step costs
+/// the same.
+pub fn bfs<S, N, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: N,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut nodes: Vec<(S, Option<usize>)> = Vec::new();
+    let mut seen: HashSet<S> = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if seen.insert(start.clone()) {
+            nodes.push((start, None));
+            queue.push_back(nodes.len() - 1);
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(index) = queue.pop_front() {
+        visited += 1;
+        let state = nodes[index].0.clone();
+        if is_goal(&state) {
+            let path = path(&nodes, index);
+            return Some(Found { cost: path.len() - 1, path, visited });
+        }
+        for next in neighbours(&state) {
+            if seen.insert(next.clone()) {
+                nodes.push((next, Some(index)));
+                queue.push_back(nodes.len() - 1);
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest path when steps have different non-negative costs. `neighbours` yields each next
+/// state with the cost of moving to it.
+pub fn dijkstra<S, C, N, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: N,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::zero(), is_goal)
+}
+
+/// Dijkstra guided by `heuristic`, an estimate of the remaining cost from a state. The result is
+/// only guaranteed cheapest if the estimate never exceeds the true remaining cost.
+pub fn astar<S, C, N, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: N,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut nodes: Vec<(S, Option<usize>)> = Vec::new();
+    // The cheapest known cost to each state, and the arena entry that reached it that cheaply.
+    let mut best: HashMap<S, (C, usize)> = HashMap::new();
+    let mut open = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = best.entry(start.clone()) {
+            nodes.push((start, None));
+            let index = nodes.len() - 1;
+            open.push(Reverse((heuristic(&nodes[index].0), C::zero(), index)));
+            entry.insert((C::zero(), index));
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(Reverse((_, cost, index))) = open.pop() {
+        let state = nodes[index].0.clone();
+        if best[&state].1 != index {
+            // Superseded by a cheaper route pushed after this one.
+            continue;
+        }
+        visited += 1;
+        if is_goal(&state) {
+            return Some(Found {
+                cost,
+                path: path(&nodes, index),
+                visited,
+            });
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|(known, _)| next_cost < *known) {
+                nodes.push((next.clone(), Some(index)));
+                let next_index = nodes.len() - 1;
+                open.push(Reverse((next_cost + heuristic(&next), next_cost, next_index)));
+                best.insert(next, (next_cost, next_index));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::IVec2;
+
+    use super::*;
+    use crate::utils::Grid;
+
+    const MAZE: &str = "\
+.9..
+.#..
+....";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(MAZE, Ok).unwrap()
+    }
+
+    fn manhattan(a: IVec2, b: IVec2) -> u32 {
+        a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+    }
+
+    fn cost(c: char) -> u32 {
+        c.to_digit(10).unwrap_or(1)
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let grid = grid();
+        let goal = IVec2::new(3, 0);
+        let found = bfs(
+            [IVec2::ZERO],
+            |p| grid.neighbours(*p).filter(|n| grid[*n] != '#').collect::<Vec<_>>(),
+            |p| *p == goal,
+        )
+        .unwrap();
+        // Straight through the 9, which bfs doesn't weigh.
+        assert_eq!(found.cost, 3);
+        assert_eq!(found.path, [IVec2::ZERO, IVec2::new(1, 0), IVec2::new(2, 0), goal]);
+        assert!(found.path.windows(2).all(|w| manhattan(w[0], w[1]) == 1));
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        let grid = grid();
+        let goal = IVec2::new(3, 0);
+        let neighbours = |p: &IVec2| {
+            grid.neighbours(*p)
+                .filter(|n| grid[*n] != '#')
+                .map(|n| (n, cost(grid[n])))
+                .collect::<Vec<_>>()
+        };
+        let slow = dijkstra([IVec2::ZERO], neighbours, |p| *p == goal).unwrap();
+        let fast = astar(
+            [IVec2::ZERO],
+            neighbours,
+            |p| manhattan(*p, goal),
+            |p| *p == goal,
+        )
+        .unwrap();
+        // Around the wall costs less than through the 9.
+        assert_eq!(slow.cost, 7);
+        assert_eq!(fast.cost, slow.cost);
+        assert_eq!(slow.path.len(), 8);
+        assert!(!fast.path.contains(&IVec2::new(1, 0)));
+        assert!(fast.visited <= slow.visited);
+    }
+
+    #[test]
+    fn unreachable_goals() {
+        let grid = grid();
+        let walled = |p: &IVec2| grid.neighbours(*p).filter(|n| grid[*n] != '#').collect::<Vec<_>>();
+        assert_eq!(bfs([IVec2::ZERO], walled, |p| *p == IVec2::new(9, 9)), None);
+    }
+}
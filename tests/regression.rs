@@ -0,0 +1,104 @@
+//! Runs every recorded answer in `tests/answers.tsv`, plus any example metadata saved by
+//! `aoc examples`, against the current solutions.
+
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use advent_2023::answers::{self, Expected};
+use advent_2023::days;
+use advent_2023::input::{self, Source};
+use anyhow::{bail, Result};
+
+enum Outcome {
+    Pass,
+    Skip(String),
+    Fail(String),
+}
+
+fn run(case: &Expected) -> Result<Outcome> {
+    let path = case.input_path();
+    if !path.is_file() {
+        return Ok(Outcome::Skip(format!("{} is missing", path.display())));
+    }
+    if fs::metadata(&path)?.len() == 0 {
+        return Ok(Outcome::Skip(format!("{} is empty, nothing to check yet", path.display())));
+    }
+    let Some(day) = days::get(case.day) else {
+        bail!("day {} is not registered", case.day);
+    };
+    let parsed = (day.parse)(input::load(case.day, &Source::Path(path))?)?;
+    let actual = match case.part {
+        1 => (day.part_one)(parsed.as_ref())?,
+        2 => (day.part_two)(parsed.as_ref())?,
+        part => bail!("there is no part {part}"),
+    };
+    if actual == case.expected {
+        Ok(Outcome::Pass)
+    } else {
+        Ok(Outcome::Fail(format!("expected {}, got {actual}", case.expected)))
+    }
+}
+
+fn cases() -> Result<Vec<Expected>> {
+    let mut cases = answers::read(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/answers.tsv"))?;
+    if let Ok(entries) = fs::read_dir(input::inputs_dir()) {
+        let mut metadata = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| {
+                let name = p.file_name().unwrap_or_default().to_string_lossy();
+                name.starts_with("examples-") && name.ends_with(".tsv")
+            })
+            .collect::<Vec<_>>();
+        metadata.sort();
+        for path in metadata {
+            for case in answers::read(&path)? {
+                if !cases.contains(&case) {
+                    cases.push(case);
+                }
+            }
+        }
+    }
+    cases.sort_by_key(|c| (c.day, c.part));
+    Ok(cases)
+}
+
+fn main() -> ExitCode {
+    let cases = match cases() {
+        Ok(cases) => cases,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut skipped, mut failed) = (0, 0, 0);
+    for case in &cases {
+        let name = format!("day {:02} part {} ({})", case.day, case.part, case.input);
+        match run(case) {
+            Ok(Outcome::Pass) => {
+                passed += 1;
+                println!("{name} ... ok");
+            }
+            Ok(Outcome::Skip(why)) => {
+                skipped += 1;
+                println!("{name} ... skipped: {why}");
+            }
+            Ok(Outcome::Fail(why)) => {
+                failed += 1;
+                println!("{name} ... FAILED: {why}");
+            }
+            Err(err) => {
+                failed += 1;
+                println!("{name} ... FAILED: {err:#}");
+            }
+        }
+    }
+
+    println!("\n{passed} passed; {skipped} skipped; {failed} failed");
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}